@@ -0,0 +1,66 @@
+//! Measures how long classifying the field types of a file with thousands of
+//! fields takes. Run with `cargo bench`.
+//!
+//! Fails when a field costs as much as compiling a single type regex, e.g. if
+//! the type regexes were compiled again for every field.
+
+use regex::Regex;
+use rust_dart_gen::{Config, DartFile};
+use std::time::{Duration, Instant};
+
+const FIELDS: usize = 5000;
+const RUNS: usize = 5;
+
+const TYPES: &[&str] = &[
+    "String",
+    "int?",
+    "List<int>",
+    "List<List<double>>?",
+    "Set<String>",
+    "Iterable<bool>",
+    "Map<String, int>",
+    "Map<String, List<Address?>>",
+    "(int, String)",
+    "Address?",
+];
+
+/// Source of a single annotated class with varied field types
+fn source() -> String {
+    let mut source = "part 'fields.flu.dart';\n\n// @flu\nabstract class _Fields {\n".to_string();
+    for field in 0..FIELDS {
+        source += &format!("  {} get field{field};\n", TYPES[field % TYPES.len()]);
+    }
+    source + "}\n"
+}
+
+/// Best of several runs, the first one including the compilation of the shared regexes
+fn best(mut run: impl FnMut()) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let source = source();
+    let config = Config::default();
+    let parse = best(|| {
+        let file = DartFile::from_string(&source, "fields.dart", &config);
+        assert!(!file.classes.is_empty());
+    });
+    let per_field = parse / FIELDS as u32;
+    // a generic type regex, as once compiled for every field
+    let compile = best(|| {
+        Regex::new(r"^List<(.+)>\??$").unwrap();
+    });
+    println!("{FIELDS} fields: {parse:>10.2?}, {per_field:.2?} per field");
+    println!("regex compilation: {compile:.2?}");
+    assert!(
+        per_field < compile,
+        "a field takes {per_field:.2?} to classify, as long as compiling a regex ({compile:.2?})"
+    );
+}
@@ -0,0 +1,88 @@
+//! Measures how generating a synthetic corpus scales with the number of
+//! threads, splitting the files as the command does. Run with `cargo bench`.
+//!
+//! Only reports the speedup and its share of the ideal one, e.g. low if the
+//! shared regexes serialized the threads, as wall-clock timings vary too much
+//! on loaded machines to fail on them.
+
+use rust_dart_gen::{Config, DartFile};
+use std::{
+    thread::{self, available_parallelism},
+    time::{Duration, Instant},
+};
+
+const FILES: usize = 400;
+const CLASSES_PER_FILE: usize = 10;
+const FIELDS_PER_CLASS: usize = 20;
+const RUNS: usize = 3;
+
+const TYPES: &[&str] = &[
+    "String",
+    "int?",
+    "double",
+    "bool",
+    "DateTime?",
+    "List<String>",
+    "Map<String, int>",
+    "Set<int>?",
+    "List<Map<String, double>>",
+    "Address",
+];
+
+/// Source of a file with annotated classes of varied field types
+fn source(file: usize) -> String {
+    let mut source = format!("part 'file{file}.flu.dart';\n");
+    for class in 0..CLASSES_PER_FILE {
+        source += &format!("\n// @flu\nabstract class _Model{file}x{class} {{\n");
+        source += &format!("  const _Model{file}x{class}();\n\n");
+        for field in 0..FIELDS_PER_CLASS {
+            let typ = TYPES[(class + field) % TYPES.len()];
+            source += &format!("  {typ} get field{field};\n");
+        }
+        source += "}\n";
+    }
+    source
+}
+
+/// Generates every file with the given number of threads
+fn generate(sources: &[String], threads: usize, config: &Config) -> Duration {
+    let start = Instant::now();
+    let chunk_size = sources.len().div_ceil(threads).max(1);
+    thread::scope(|scope| {
+        for part in sources.chunks(chunk_size) {
+            scope.spawn(move || {
+                for (i, source) in part.iter().enumerate() {
+                    let file = DartFile::from_string(source, &format!("file{i}.dart"), config);
+                    assert!(file.generate_string(config).is_some());
+                }
+            });
+        }
+    });
+    start.elapsed()
+}
+
+fn main() {
+    let sources: Vec<String> = (0..FILES).map(source).collect();
+    let config = Config::default();
+    let cores = available_parallelism().map_or(1, |n| n.get());
+    let mut thread_counts = vec![1, 4, cores];
+    thread_counts.sort();
+    thread_counts.dedup();
+
+    let mut single = Duration::ZERO;
+    for threads in thread_counts {
+        let best = (0..RUNS)
+            .map(|_| generate(&sources, threads, &config))
+            .min()
+            .unwrap();
+        if threads == 1 {
+            single = best;
+        }
+        let speedup = single.as_secs_f64() / best.as_secs_f64();
+        let ideal = threads.min(cores) as f64;
+        println!(
+            "{threads:>3} threads: {best:>10.2?}, {speedup:.2}x speedup (ideal {ideal}x, {:.0}%)",
+            speedup / ideal * 100.0
+        );
+    }
+}
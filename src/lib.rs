@@ -0,0 +1,3628 @@
+//! Generates Dart data classes from the `// @flu` annotated abstract classes of
+//! a Dart library, as done by the `rust_dart_gen` command.
+//!
+//! ```
+//! use rust_dart_gen::{Config, DartFile};
+//!
+//! let source = "// @flu\nabstract class _User {\n  String get name;\n}\n";
+//! let file = DartFile::from_string(source, "user.dart", &Config::default());
+//! let generated = file.generate_string(&Config::default()).unwrap();
+//! assert!(generated.contains("class User extends _User {"));
+//! ```
+
+use clap::{Parser, ValueEnum};
+use glob::{MatchOptions, Pattern};
+use regex::Regex;
+use std::{
+    collections::{HashMap, HashSet},
+    env, fmt, fs, io,
+    path::{Component, Path, PathBuf},
+    sync::LazyLock,
+};
+
+static FIELD_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s\s([A-Za-z_(].*) get (\w+);$").unwrap());
+static FIELD_OPTIONS_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?P<key>\w+)(?:=(?P<value>"[^"]+"|const \S+|\S+))?"#).unwrap());
+static FUNCTION_TYPE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bFunction\b").unwrap());
+static JSON_KEY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s*@JsonKey\((?:.*\bname:\s*['"]([^'"]+)['"])?.*\)$"#).unwrap()
+});
+static TYPEDEF_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^typedef (\w+) = ([^;]+);").unwrap());
+static ARROW_GETTER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s\s[A-Za-z_(].* get (\w+)\s*=>").unwrap());
+static DEPRECATED_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*@(?:Deprecated\(.*\)|deprecated)\s*$").unwrap());
+static COPY_WITH_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s\s[A-Za-z_][\w<>?, ]*\s+copyWith\s*\(").unwrap());
+/// `class Name {` declaration of a regular class, only read with `include_private`
+static PLAIN_CLASS_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:abstract )?class ([A-Z]\w*) \{").unwrap());
+static ENUM_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^enum (\w+)\b").unwrap());
+/// Type declared by a file, as in `class Name`, `sealed class Name` or `mixin Name`
+static TYPE_DECLARATION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:(?:abstract|base|final|interface|sealed|mixin)\s+)*(?:class|mixin|enum|typedef)\s+(\w+)")
+        .unwrap()
+});
+static WORD_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\w+").unwrap());
+
+/// Dart reserved words, which can't be used as field names
+const DART_RESERVED_WORDS: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+    "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+    "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "while",
+    "with",
+];
+
+/// Dart and Flutter types holding resources or behavior rather than data, which
+/// would otherwise be decoded as custom classes with a `fromJson`
+const UNSERIALIZABLE_TYPES: &[&str] = &[
+    "AnimationController",
+    "BuildContext",
+    "ChangeNotifier",
+    "Completer",
+    "Directory",
+    "Expando",
+    "File",
+    "Finalizer",
+    "FocusNode",
+    "Future",
+    "FutureOr",
+    "GlobalKey",
+    "HttpClient",
+    "HttpServer",
+    "Isolate",
+    "Iterator",
+    "Process",
+    "Random",
+    "ReceivePort",
+    "RegExp",
+    "SendPort",
+    "ServerSocket",
+    "Sink",
+    "Socket",
+    "StackTrace",
+    "Stream",
+    "StreamController",
+    "StreamSink",
+    "StreamSubscription",
+    "Symbol",
+    "TextEditingController",
+    "Timer",
+    "Type",
+    "ValueNotifier",
+    "WeakReference",
+    "Widget",
+    "Zone",
+];
+
+/// Suffix of the class generated for a regular class, as in `UserData` for `class User {`
+const PLAIN_CLASS_SUFFIX: &str = "Data";
+
+/// File with gitignore style patterns of source files to skip
+pub const IGNORE_FILE: &str = ".fluignore";
+
+/// Options that affect the generated code
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub key_case: KeyCase,
+    pub output: Option<PathBuf>,
+    pub stdout: bool,
+    pub equatable: bool,
+    pub annotation: Annotation,
+    pub base_name: BaseName,
+    pub extension: String,
+    pub const_constructors: bool,
+    pub map_aliases: bool,
+    pub raw_json: bool,
+    pub omit_nulls: bool,
+    pub indent: usize,
+    pub check: bool,
+    pub runtime_type_tostring: bool,
+    pub merge: bool,
+    pub nested_copy_with: bool,
+    pub immutable: bool,
+    pub exact_type_equals: bool,
+    /// Non-final fields, only enabled by the `mutable` class directive
+    pub mutable: bool,
+    /// Positional constructor parameters, only enabled by the `positional` class directive
+    pub positional: bool,
+    pub optional_nullable: bool,
+    pub coerce_numbers: bool,
+    /// Also generate a standalone `<Name>Data` class for `class Name {`
+    pub include_private: bool,
+    pub assert_required: bool,
+    pub annotate_keys: bool,
+    /// Generated files importing the source library instead of being a part of it
+    pub standalone: bool,
+    pub copy_with_map: bool,
+    /// Generated code left to `dart format` instead of starting with `// dart format off`
+    pub format_on: bool,
+    pub trailing_comma: TrailingComma,
+    pub copy_with_style: CopyWithStyle,
+    /// Key and value of a type entry in JSON, only set by the `discriminator`
+    /// class directive. The value defaults to the class name.
+    pub discriminator: Option<(String, Option<String>)>,
+    pub verbose: bool,
+    pub ignore: IgnoreFile,
+}
+impl Default for Config {
+    /// Same as the defaults of the command line options, without an ignore file
+    fn default() -> Self {
+        Self {
+            key_case: KeyCase::None,
+            output: None,
+            stdout: false,
+            equatable: false,
+            annotation: Annotation::new("flu"),
+            base_name: BaseName::new("_", ""),
+            extension: "flu".to_string(),
+            const_constructors: false,
+            map_aliases: false,
+            raw_json: false,
+            omit_nulls: false,
+            indent: 2,
+            check: false,
+            runtime_type_tostring: false,
+            merge: false,
+            nested_copy_with: false,
+            immutable: false,
+            exact_type_equals: false,
+            mutable: false,
+            positional: false,
+            optional_nullable: false,
+            coerce_numbers: false,
+            include_private: false,
+            assert_required: false,
+            annotate_keys: false,
+            standalone: false,
+            copy_with_map: false,
+            format_on: false,
+            trailing_comma: TrailingComma::Always,
+            copy_with_style: CopyWithStyle::Sentinel,
+            discriminator: None,
+            verbose: false,
+            ignore: IgnoreFile::default(),
+        }
+    }
+}
+
+/// Command line options of the `rust_dart_gen` command
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None, args_override_self = true)]
+pub struct Args {
+    /// Glob patterns, directories or paths of dart files, repeated or comma separated
+    #[arg(short, long, default_value = "lib/**/*.dart", value_delimiter = ',')]
+    pub path: Vec<String>,
+
+    /// Naming convention of JSON keys, unless a field sets its own key
+    #[arg(long, value_enum, default_value_t = KeyCase::None)]
+    pub key_case: KeyCase,
+
+    /// Number of worker threads [default: number of logical CPUs]
+    #[arg(long)]
+    pub threads: Option<usize>,
+
+    /// Stop at the first file with an error instead of processing every file
+    #[arg(long, conflicts_with = "watch")]
+    pub fail_fast: bool,
+
+    /// Directory for generated files, mirroring the source tree [default: next to the source]
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Keep running and regenerate files when they change, checking them every 200 ms
+    #[arg(short, long)]
+    pub watch: bool,
+
+    /// Print generated code to stdout instead of writing files
+    #[arg(long, visible_alias = "dry-run")]
+    pub stdout: bool,
+
+    /// Use `EquatableMixin` from package:equatable for equality
+    #[arg(long)]
+    pub equatable: bool,
+
+    /// Annotation name used in the `// @<name>` comments
+    #[arg(long, default_value = "flu")]
+    pub annotation: String,
+
+    /// Prefix of the annotated abstract class names, as in `abstract class _Name`
+    #[arg(long, default_value = "_")]
+    pub base_prefix: String,
+
+    /// Suffix of the annotated abstract class names, as in `abstract class NameBase`
+    #[arg(long, default_value = "")]
+    pub base_suffix: String,
+
+    /// Extension of the generated files, as in `<name>.<extension>.dart`
+    #[arg(long, default_value = "flu")]
+    pub extension: String,
+
+    /// Generate const constructors for all classes, the abstract classes
+    /// still need a `const _Name();` constructor for them to compile
+    #[arg(long = "const")]
+    pub const_constructors: bool,
+
+    /// Also generate `fromMap`/`toMap`, forwarding to `fromJson`/`toJson`
+    #[arg(long)]
+    pub map_aliases: bool,
+
+    /// Also generate `fromRawJson`/`toRawJson`, for JSON strings
+    #[arg(long)]
+    pub raw_json: bool,
+
+    /// Leave null values of nullable fields out of `toJson`
+    #[arg(long)]
+    pub omit_nulls: bool,
+
+    /// Read a single file from stdin and print the generated file to stdout
+    #[arg(long)]
+    pub stdin: bool,
+
+    /// Path of the file read from stdin, used in `part of` and diagnostics
+    #[arg(long, requires = "stdin")]
+    pub stdin_name: Option<String>,
+
+    /// Write a Dart file exporting every library with a generated part
+    #[arg(long)]
+    pub index: Option<PathBuf>,
+
+    /// Number of spaces per indentation level of the generated code
+    #[arg(long, default_value_t = 2)]
+    pub indent: usize,
+
+    /// Check that the generated files are up to date without writing them,
+    /// exits with an error when any of them would change
+    #[arg(long, conflicts_with_all = ["stdout", "watch"])]
+    pub check: bool,
+
+    /// Use `$runtimeType` instead of the class name in `toString`
+    #[arg(long)]
+    pub runtime_type_tostring: bool,
+
+    /// Generate `merge`, copying the fields of another instance. Nullable fields
+    /// of the other instance override only when they are not null.
+    #[arg(long)]
+    pub merge: bool,
+
+    /// Generate `copyWith<Field>` for non-nullable custom fields, updating the
+    /// nested object with a function, e.g. `(a) => a.copyWith(city: 'Paris')`
+    #[arg(long)]
+    pub nested_copy_with: bool,
+
+    /// Annotate generated classes with `@immutable` from package:meta
+    #[arg(long)]
+    pub immutable: bool,
+
+    /// Compare the `runtimeType` in `==`, so instances of a subclass are never
+    /// equal to instances of its parent
+    #[arg(long)]
+    pub exact_type_equals: bool,
+
+    /// How `copyWith` can set nullable fields to null. `sentinel` by default, so
+    /// that `copyWith(name: null)` keeps clearing a field as in earlier versions
+    #[arg(long = "copywith-style", value_enum, default_value_t = CopyWithStyle::Sentinel)]
+    pub copy_with_style: CopyWithStyle,
+
+    /// Make the constructor parameters of nullable fields optional instead of `required`
+    #[arg(long)]
+    pub optional_nullable: bool,
+
+    /// Decode number fields from numeric strings too, as the `coerce` field option
+    #[arg(long)]
+    pub coerce_numbers: bool,
+
+    /// Also generate a standalone `<Name>Data` class for `// @flu` classes not
+    /// named as base classes, as in `class Name {`
+    #[arg(long)]
+    pub include_private: bool,
+
+    /// Throw a `FormatException` naming the missing key when a required field
+    /// is null in `fromJson`, instead of the `TypeError` of the cast
+    #[arg(long)]
+    pub assert_required: bool,
+
+    /// Comment each generated field with its JSON key, as in `// json: 'created_at'`
+    #[arg(long)]
+    pub annotate_keys: bool,
+
+    /// Generate self-contained libraries importing the source file instead of
+    /// parts of it, with classes extending nothing
+    #[arg(long)]
+    pub standalone: bool,
+
+    /// Generate `copyWithMap`, decoding the fields whose JSON key is in the
+    /// given map and keeping the others
+    #[arg(long)]
+    pub copy_with_map: bool,
+
+    /// Format the generated code with `dart format` instead of disabling
+    /// formatting, left unformatted with a warning when it fails
+    #[arg(long)]
+    pub format_on: bool,
+
+    /// Trailing commas after the last element of the generated multi-line
+    /// argument lists, parameter lists and collection literals
+    #[arg(long, value_enum, default_value_t = TrailingComma::Always)]
+    pub trailing_comma: TrailingComma,
+
+    /// Log the classes and fields found in each file, and the skipped files
+    #[arg(short, long)]
+    pub verbose: bool,
+}
+
+impl Args {
+    /// Options of the generated code given on the command line, failing for
+    /// options that can't generate valid code
+    pub fn config(&self) -> Result<Config, String> {
+        if self.base_prefix.is_empty() && self.base_suffix.is_empty() {
+            return Err(
+                "`--base-prefix` and `--base-suffix` can't both be empty, generated classes would extend themselves"
+                    .to_string(),
+            );
+        }
+        Ok(Config {
+            key_case: self.key_case,
+            output: self.output.clone(),
+            stdout: self.stdout,
+            equatable: self.equatable,
+            annotation: Annotation::new(&self.annotation),
+            base_name: BaseName::new(&self.base_prefix, &self.base_suffix),
+            extension: self.extension.clone(),
+            const_constructors: self.const_constructors,
+            map_aliases: self.map_aliases,
+            raw_json: self.raw_json,
+            omit_nulls: self.omit_nulls,
+            indent: self.indent,
+            check: self.check,
+            runtime_type_tostring: self.runtime_type_tostring,
+            merge: self.merge,
+            nested_copy_with: self.nested_copy_with,
+            immutable: self.immutable,
+            exact_type_equals: self.exact_type_equals,
+            mutable: false,
+            positional: false,
+            optional_nullable: self.optional_nullable,
+            coerce_numbers: self.coerce_numbers,
+            include_private: self.include_private,
+            assert_required: self.assert_required,
+            annotate_keys: self.annotate_keys,
+            standalone: self.standalone,
+            copy_with_map: self.copy_with_map,
+            format_on: self.format_on,
+            trailing_comma: self.trailing_comma,
+            copy_with_style: self.copy_with_style,
+            discriminator: None,
+            verbose: self.verbose,
+            ignore: IgnoreFile::from_file(Path::new(IGNORE_FILE)),
+        })
+    }
+}
+
+/// Patterns of a `.fluignore` file, relative to the current directory
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreFile {
+    patterns: Vec<IgnorePattern>,
+}
+impl IgnoreFile {
+    /// A missing file ignores nothing
+    pub fn from_file(path: &Path) -> Self {
+        let content = fs::read_to_string(path).unwrap_or_default();
+        let patterns = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(IgnorePattern::new)
+            .collect();
+        Self { patterns }
+    }
+
+    /// The last matching pattern decides, as in gitignore
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let components: Vec<String> = relative_to_current_dir(path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        self.patterns
+            .iter()
+            .rev()
+            .find(|p| p.matches(&components))
+            .is_some_and(|p| !p.negated)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IgnorePattern {
+    pattern: Pattern,
+    /// `!pattern`, including a previously ignored path again
+    negated: bool,
+    /// `pattern/`, only matching directories
+    dir_only: bool,
+    /// Containing a `/`, matched from the current directory instead of any directory
+    anchored: bool,
+}
+impl IgnorePattern {
+    fn new(line: &str) -> Option<Self> {
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let anchored = line.contains('/');
+        let pattern = Pattern::new(line.trim_start_matches('/')).ok()?;
+        Some(Self {
+            pattern,
+            negated,
+            dir_only,
+            anchored,
+        })
+    }
+
+    fn matches(&self, components: &[String]) -> bool {
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+        // the path itself is not a directory
+        let last = if self.dir_only {
+            components.len().saturating_sub(1)
+        } else {
+            components.len()
+        };
+        if self.anchored {
+            (1..=last).any(|n| {
+                self.pattern
+                    .matches_with(&components[..n].join("/"), options)
+            })
+        } else {
+            components[..last]
+                .iter()
+                .any(|c| self.pattern.matches_with(c, options))
+        }
+    }
+}
+
+/// Comments recognized by the parser, derived from the annotation name
+#[derive(Debug, Clone)]
+pub struct Annotation {
+    /// `// @flu` line above a class
+    class_marker: String,
+    /// `// @flu: ` directive line
+    directive_prefix: String,
+    /// `  // @flu key="..."` options line above a field
+    field_regex: Regex,
+}
+impl Annotation {
+    pub fn new(name: &str) -> Self {
+        Self {
+            class_marker: format!("// @{name}"),
+            directive_prefix: format!("// @{name}: "),
+            field_regex: Regex::new(&format!(r"^  // @{} (.*)$", regex::escape(name))).unwrap(),
+        }
+    }
+}
+
+/// Naming of the annotated abstract classes, `_Name` by default
+#[derive(Debug, Clone)]
+pub struct BaseName {
+    prefix: String,
+    suffix: String,
+    /// `abstract class _Name<T> extends _Parent {` declaration, the type
+    /// parameters and the parent being optional
+    class_regex: Regex,
+}
+impl BaseName {
+    pub fn new(prefix: &str, suffix: &str) -> Self {
+        let (p, s) = (regex::escape(prefix), regex::escape(suffix));
+        Self {
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+            class_regex: Regex::new(&format!(
+                r"^abstract class {p}(\w+?){s}(?:<(.+?)>)?(?: extends {p}(\w+?){s})? \{{"
+            ))
+            .unwrap(),
+        }
+    }
+
+    /// Name of the abstract class of a generated class
+    fn of(&self, name: &str) -> String {
+        format!("{}{name}{}", self.prefix, self.suffix)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum KeyCase {
+    /// Same as the field name
+    #[value(alias = "camel")]
+    None,
+    /// created_at
+    Snake,
+    /// created-at
+    Kebab,
+    /// CreatedAt
+    Pascal,
+}
+impl KeyCase {
+    fn apply(&self, name: &str) -> String {
+        match self {
+            KeyCase::None => name.to_string(),
+            KeyCase::Snake => Self::words(name).join("_"),
+            KeyCase::Kebab => Self::words(name).join("-"),
+            KeyCase::Pascal => Self::words(name)
+                .iter()
+                .map(|w| {
+                    let mut chars = w.chars();
+                    match chars.next() {
+                        Some(c) => c.to_uppercase().chain(chars).collect(),
+                        None => String::new(),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Splits a camelCase name into lowercase words, keeping acronyms together
+    /// (`userID` -> `user`, `id`; `HTTPServer` -> `http`, `server`)
+    fn words(name: &str) -> Vec<String> {
+        let chars: Vec<char> = name.chars().collect();
+        let mut words: Vec<String> = vec![];
+        let mut word = String::new();
+        for (i, c) in chars.iter().enumerate() {
+            if *c == '_' {
+                if !word.is_empty() {
+                    words.push(word.to_lowercase());
+                    word.clear();
+                }
+                continue;
+            }
+            if c.is_uppercase() && !word.is_empty() {
+                let prev = chars[i - 1];
+                let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+                if !prev.is_uppercase() || next_lower {
+                    words.push(word.to_lowercase());
+                    word.clear();
+                }
+            }
+            word.push(*c);
+        }
+        if !word.is_empty() {
+            words.push(word.to_lowercase());
+        }
+        words
+    }
+}
+
+/// Dart string literal of a JSON key
+fn dart_string(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('$', "\\$");
+    format!("'{escaped}'")
+}
+
+/// Dart expression reading a JSON value, through the nested maps of a key path
+fn json_access(path: &[String]) -> String {
+    let mut access = format!("json[{}]", dart_string(&path[0]));
+    for segment in &path[1..] {
+        access = format!(
+            "({access} as Map<String, dynamic>?)?[{}]",
+            dart_string(segment)
+        );
+    }
+    access
+}
+
+/// Whether the `json` map has a key, which can be a path as for `json_access`
+fn json_contains(path: &[String]) -> String {
+    match path.split_last() {
+        Some((last, parents)) if !parents.is_empty() => format!(
+            "(({} as Map<String, dynamic>?)?.containsKey({}) ?? false)",
+            json_access(parents),
+            dart_string(last)
+        ),
+        _ => format!("json.containsKey({})", dart_string(&path[0])),
+    }
+}
+
+/// Indentation of the given nesting level
+fn indent(level: usize, unit: usize) -> String {
+    " ".repeat(level * unit)
+}
+
+/// Keeps at most one blank line in a row, and a single newline at the end
+fn collapse_blank_lines(code: &str) -> String {
+    let mut collapsed = String::new();
+    for line in code.trim_end().split('\n') {
+        let line = line.trim_end();
+        if line.is_empty() && (collapsed.is_empty() || collapsed.ends_with("\n\n")) {
+            continue;
+        }
+        collapsed.push_str(line);
+        collapsed.push('\n');
+    }
+    collapsed
+}
+
+/// Changes the indentation of generated code, written with 2 spaces per level
+fn reindent(code: &str, unit: usize) -> String {
+    if unit == 2 {
+        return code.to_string();
+    }
+    code.split('\n')
+        .map(|line| {
+            let content = line.trim_start_matches(' ');
+            let level = (line.len() - content.len()) / 2;
+            indent(level, unit) + content
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Int value of a number expression, throwing `FormatException` for a number
+/// with a fraction. An integral double, as `5.0`, is accepted.
+fn integral(number: &str) -> String {
+    format!(
+        "switch ({number}) {{ final n when n == n.toInt() => n.toInt(), final n => throw FormatException('Expected an integer', n) }}"
+    )
+}
+
+/// Splits a list of types at the commas outside of brackets, as in `int, Map<String, int>`
+fn split_top_level(types: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in types.char_indices() {
+        match c {
+            '<' | '(' | '{' | '[' => depth += 1,
+            '>' | ')' | '}' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(types[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(types[start..].trim());
+    parts
+}
+
+/// Characters of a line outside of string literals, with their byte index
+fn code_chars(line: &str) -> Vec<(usize, char)> {
+    let mut chars = vec![];
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => quote = Some(c),
+            None => chars.push((i, c)),
+        }
+    }
+    chars
+}
+
+/// Line without its `//` comment, ignoring `//` in string literals
+fn strip_comment(line: &str) -> &str {
+    let chars = code_chars(line);
+    let comment = chars
+        .windows(2)
+        .find(|w| w[0].1 == '/' && w[1].1 == '/' && w[1].0 == w[0].0 + 1);
+    match comment {
+        Some(w) => &line[..w[0].0],
+        None => line,
+    }
+}
+
+/// String literal or comment continuing on the next line
+#[derive(Debug, Clone, Copy)]
+enum MultiLine {
+    /// `/* */` comment, with its nesting depth
+    Comment(usize),
+    /// `'''` or `"""` string, with its quote and whether it is raw
+    String(char, bool),
+}
+
+/// A source line as seen by the parser
+#[derive(Debug, Default)]
+struct CodeLine {
+    /// Whether the line starts inside a multi-line string or comment
+    continued: bool,
+    /// Line without its comments
+    uncommented: String,
+    /// Line without its comments and string literals
+    code: String,
+}
+
+/// Lines of a file without their comments and string literals, which can span
+/// several lines
+fn code_lines(lines: &[String]) -> Vec<CodeLine> {
+    let mut multi_line: Option<MultiLine> = None;
+    let mut code_lines = vec![];
+    for line in lines {
+        let mut code_line = CodeLine {
+            continued: multi_line.is_some(),
+            ..CodeLine::default()
+        };
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            let rest = &chars[i..];
+            // characters of a string literal, kept in the uncommented line
+            let string_start = i;
+            match multi_line {
+                Some(MultiLine::Comment(depth)) if rest.starts_with(&['*', '/']) => {
+                    multi_line = (depth > 1).then_some(MultiLine::Comment(depth - 1));
+                    i += 2;
+                    continue;
+                }
+                // block comments nest in Dart
+                Some(MultiLine::Comment(depth)) if rest.starts_with(&['/', '*']) => {
+                    multi_line = Some(MultiLine::Comment(depth + 1));
+                    i += 2;
+                    continue;
+                }
+                Some(MultiLine::Comment(_)) => {
+                    i += 1;
+                    continue;
+                }
+                Some(MultiLine::String(_, false)) if chars[i] == '\\' => i += 2,
+                Some(MultiLine::String(quote, _)) if rest.starts_with(&[quote; 3]) => {
+                    multi_line = None;
+                    i += 3;
+                }
+                Some(MultiLine::String(..)) => i += 1,
+                None if rest.starts_with(&['/', '/']) => break,
+                None if rest.starts_with(&['/', '*']) => {
+                    multi_line = Some(MultiLine::Comment(1));
+                    i += 2;
+                    continue;
+                }
+                None if chars[i] == '\'' || chars[i] == '"' => {
+                    let quote = chars[i];
+                    // a `r` prefix, as in `r'\d+'`, and not the end of a name
+                    let code = &code_line.code;
+                    let raw = code.ends_with('r')
+                        && !code[..code.len() - 1]
+                            .ends_with(|c: char| c.is_alphanumeric() || c == '_');
+                    if raw {
+                        code_line.code.pop();
+                    }
+                    if rest.starts_with(&[quote; 3]) {
+                        multi_line = Some(MultiLine::String(quote, raw));
+                        i += 3;
+                    } else {
+                        i += 1;
+                        while i < chars.len() && chars[i] != quote {
+                            i += if !raw && chars[i] == '\\' { 2 } else { 1 };
+                        }
+                        i += 1;
+                    }
+                }
+                None => {
+                    code_line.code.push(chars[i]);
+                    code_line.uncommented.push(chars[i]);
+                    i += 1;
+                    continue;
+                }
+            }
+            let end = i.min(chars.len());
+            code_line.uncommented.extend(&chars[string_start..end]);
+        }
+        code_lines.push(code_line);
+    }
+    code_lines
+}
+
+/// Net change of the brace depth over the code of a line, as given by `code_lines`
+fn brace_depth_change(code: &str) -> i32 {
+    code.chars().fold(0, |depth, c| match c {
+        '{' => depth + 1,
+        '}' => depth - 1,
+        _ => depth,
+    })
+}
+
+/// Removes the whitespace around the punctuation of a type, as in `Map< String , int >`
+fn normalize_type(typ: &str) -> String {
+    let mut normalized = String::new();
+    for c in typ.split_whitespace().collect::<Vec<_>>().join(" ").chars() {
+        // a space after `>` or `?` can separate words, as in `int? Function()`
+        if c == ' ' && normalized.ends_with(['<', ',']) {
+            continue;
+        }
+        if matches!(c, '<' | '>' | ',' | '?') && normalized.ends_with(' ') {
+            normalized.pop();
+        }
+        // a trailing comma of type arguments, as in `Map<String, int,>`
+        if c == '>' && normalized.ends_with(',') {
+            normalized.pop();
+        }
+        normalized.push(c);
+    }
+    normalized
+}
+
+/// Whether the brackets of a type are balanced and no type argument is empty,
+/// as `List<Map<String, int>` would otherwise generate broken code
+fn is_well_formed_type(typ: &str) -> bool {
+    let mut depth = 0;
+    for c in typ.chars() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            _ => {}
+        }
+        if depth < 0 {
+            return false;
+        }
+    }
+    depth == 0 && !typ.contains("<>") && !typ.contains("<,") && !typ.contains(",,")
+}
+
+/// Type arguments of a generic type, as `["String", "List<int>"]` for
+/// `Map<String, List<int>>?` and the `Map` generic. `None` for other types.
+fn type_arguments<'a>(typ: &'a str, generic: &str) -> Option<Vec<&'a str>> {
+    let inner = typ.strip_prefix(generic)?.strip_prefix('<')?;
+    let inner = inner.strip_suffix('?').unwrap_or(inner).strip_suffix('>')?;
+    // the closing bracket must match the opening one, unlike in `List<int> Function(List<int>)`
+    is_well_formed_type(inner).then(|| split_top_level(inner))
+}
+
+/// Argument of a generated constructor call, named unless the constructor is positional
+fn argument(name: &str, value: &str, config: &Config) -> String {
+    if config.positional {
+        value.to_string()
+    } else {
+        format!("{name}: {value}")
+    }
+}
+
+/// Replaces the typedef aliases in a type by the types they alias, as in
+/// `UserId?` for `String?` with `typedef UserId = String;`
+fn resolve_typedefs(typ: &str, typedefs: &HashMap<String, String>) -> String {
+    let mut resolved = typ.to_string();
+    // bounded, so aliases referring to each other don't loop forever
+    for _ in 0..typedefs.len() {
+        let next = WORD_REGEX.replace_all(&resolved, |cap: &regex::Captures| {
+            typedefs
+                .get(&cap[0])
+                .cloned()
+                .unwrap_or_else(|| cap[0].to_string())
+        });
+        if next == resolved {
+            break;
+        }
+        resolved = next.to_string();
+    }
+    // an alias of a nullable type used as nullable
+    resolved.replace("??", "?")
+}
+
+/// A member declared in a class body that is not a field, a method or a constructor
+fn is_unrecognized_member(line: &str) -> bool {
+    let line = line.trim();
+    line.ends_with(';')
+        && !line.contains(['(', '='])
+        && !line.starts_with("static ")
+        && !line.starts_with('}')
+}
+
+#[derive(Debug)]
+pub struct DartFile {
+    path: String,
+    pub classes: Vec<DartClass>,
+    diagnostics: Vec<Diagnostic>,
+}
+impl DartFile {
+    fn new(path: String, classes: Vec<DartClass>, diagnostics: Vec<Diagnostic>) -> Self {
+        Self {
+            path,
+            classes,
+            diagnostics,
+        }
+    }
+
+    pub fn from_file(path: &str, config: &Config) -> Result<Self, std::io::Error> {
+        let content = fs::read_to_string(path)?;
+        Ok(DartFile::from_string(&content, path, config))
+    }
+
+    pub fn from_string(content: &str, path: &str, config: &Config) -> Self {
+        let annotation = &config.annotation;
+        let base_name = &config.base_name;
+        // `lines` only strips a `\r` directly followed by `\n`, dropping any
+        // other trailing one so markers are still matched exactly
+        let lines: Vec<String> = content
+            .lines()
+            .map(|line| line.trim_end_matches('\r').to_string())
+            .collect();
+        let mut classes: Vec<DartClass> = vec![];
+        let mut diagnostics: Vec<Diagnostic> = vec![];
+
+        let mut annotation_start = false;
+        let mut class_start = false;
+        let mut class_line = 0;
+        let mut class_lines: Vec<usize> = vec![];
+        let mut depth = 0; // scopes { }
+        // a field declaration spread over multiple lines: (first line, joined text)
+        let mut pending: Option<(usize, String)> = None;
+        // directives between the marker and the class declaration
+        let mut class_directives: Vec<ClassDirective> = vec![];
+        // aliases declared anywhere in the file, as in `typedef UserId = String;`
+        let typedefs: HashMap<String, String> = lines
+            .iter()
+            .filter_map(|line| TYPEDEF_REGEX.captures(line))
+            .map(|cap| (cap[1].to_string(), normalize_type(&cap[2])))
+            .collect();
+        // enums declared anywhere in the file
+        let enums: HashSet<String> = lines
+            .iter()
+            .filter_map(|line| ENUM_REGEX.captures(line))
+            .map(|cap| cap[1].to_string())
+            .collect();
+        // types declared or generated by the file, which can share the name of
+        // an unserializable type as in `class Timer`
+        let declared_types: HashSet<String> = lines
+            .iter()
+            .filter_map(|line| {
+                base_name
+                    .class_regex
+                    .captures(line)
+                    .or_else(|| TYPE_DECLARATION_REGEX.captures(line))
+            })
+            .map(|cap| cap[1].to_string())
+            .collect();
+
+        let code_lines = code_lines(&lines);
+
+        // parsing all classes and their fields in a single loop
+        for (i, line) in lines.iter().enumerate() {
+            let CodeLine {
+                continued,
+                uncommented,
+                code,
+            } = &code_lines[i];
+            if !annotation_start {
+                annotation_start = line.trim() == annotation.class_marker;
+                // a marker at the end of the class declaration, as in `abstract class _Name { // @flu`
+                let code = strip_comment(line);
+                let trailing_marker = line[code.len()..].trim() == annotation.class_marker
+                    && (base_name.class_regex.is_match(code.trim_end())
+                        || config.include_private && PLAIN_CLASS_REGEX.is_match(code.trim_end()));
+                if !trailing_marker {
+                    continue;
+                }
+                annotation_start = true;
+            }
+
+            // class directives, as in `// @flu: const, equatable`
+            if let Some(payload) = line.trim_start().strip_prefix(&annotation.directive_prefix) {
+                for name in payload.split([',', ' ']).filter(|n| !n.is_empty()) {
+                    match ClassDirective::from_str(name) {
+                        Some(directive) if class_start => {
+                            classes.last_mut().unwrap().directives.push(directive)
+                        }
+                        Some(directive) => class_directives.push(directive),
+                        None => diagnostics.push(Diagnostic::warning(
+                            i,
+                            format!("unknown directive `{name}`"),
+                        )),
+                    }
+                }
+                continue;
+            }
+
+            // removing comments from line
+            let line = uncommented.trim_end();
+            if line.trim_start().is_empty() {
+                continue;
+            }
+
+            // checking for a class declaration
+            if !class_start {
+                // metadata of the abstract class, as in `@immutable`
+                if line.starts_with('@') {
+                    continue;
+                }
+                // (generated name, type parameters, parent, declared as a regular class)
+                let declaration = if let Some(cap) = base_name.class_regex.captures(line) {
+                    Some((
+                        cap[1].to_string(),
+                        cap.get(2).map_or(vec![], |m| {
+                            split_top_level(m.as_str())
+                                .into_iter()
+                                .map(String::from)
+                                .collect()
+                        }),
+                        cap.get(3).map(|m| m.as_str().to_string()),
+                        false,
+                    ))
+                } else if config.include_private
+                    && let Some(cap) = PLAIN_CLASS_REGEX.captures(line)
+                {
+                    Some((
+                        format!("{}{PLAIN_CLASS_SUFFIX}", &cap[1]),
+                        vec![],
+                        None,
+                        true,
+                    ))
+                } else {
+                    None
+                };
+                if let Some((name, type_parameters, parent, plain)) = declaration {
+                    // start of a @flu class
+                    let mut class = DartClass::new(
+                        name,
+                        parent,
+                        false,
+                        vec![],
+                        std::mem::take(&mut class_directives),
+                    );
+                    // nothing to extend, so the constructor can always be const
+                    class.standalone = plain || config.standalone;
+                    class.has_const_constructor = class.standalone;
+                    class.type_parameters = type_parameters;
+                    classes.push(class);
+                    class_line = i;
+                    class_lines.push(i);
+                    if line.ends_with("}") {
+                        annotation_start = false;
+                        diagnostics.extend(classes.last().unwrap().const_hint(class_line, config));
+                    } else {
+                        class_start = true;
+                        depth = 1;
+                    }
+                } else {
+                    let or_plain = if config.include_private {
+                        " or `class Name {`"
+                    } else {
+                        ""
+                    };
+                    diagnostics.push(Diagnostic::error(
+                        i,
+                        format!(
+                            "`{}` must be followed by `abstract class {} {{`{or_plain}",
+                            annotation.class_marker,
+                            base_name.of("Name")
+                        ),
+                    ));
+                    annotation_start = false;
+                }
+                continue;
+            }
+
+            // members of the class, not a line continuing a multi-line string or comment
+            let members = depth == 1 && !continued;
+
+            // joining a declaration spread over multiple lines into a single line
+            let (i, line) = match pending.take() {
+                // an opening brace means it was not a field (e.g. a method)
+                Some(_) if code.contains('{') => (i, line.to_string()),
+                Some((start, partial)) => {
+                    let joined = if partial.ends_with(['<', '(']) {
+                        partial + line.trim()
+                    } else {
+                        partial + " " + line.trim()
+                    };
+                    if !line.ends_with(';') {
+                        pending = Some((start, joined));
+                        continue;
+                    }
+                    (start, joined)
+                }
+                None if members
+                    && !line.ends_with(';')
+                    && !code.contains(['{', '}'])
+                    && !line.trim_start().starts_with('@') =>
+                {
+                    pending = Some((i, line.to_string()));
+                    continue;
+                }
+                None => (i, line.to_string()),
+            };
+            let line = line.as_str();
+
+            // checking for const constructor, whatever the spacing, as in `const _Name( );`
+            if members
+                && let Some(constructor) = line.trim_start().strip_prefix("const ")
+                && constructor.split_whitespace().collect::<String>()
+                    == format!(
+                        "{}();",
+                        base_name.of(&classes.last().unwrap().abstract_name)
+                    )
+            {
+                classes.last_mut().unwrap().has_const_constructor = true;
+                continue;
+            }
+
+            // a copyWith declared by the abstract class, overridden by the generated one
+            if members && COPY_WITH_REGEX.is_match(line) && !classes.last().unwrap().standalone {
+                classes.last_mut().unwrap().declares_copy_with = true;
+            }
+
+            // a getter with a body is computed by the abstract class, not a field
+            if members && let Some(cap) = ARROW_GETTER_REGEX.captures(line) {
+                if config.verbose {
+                    diagnostics.push(Diagnostic::info(
+                        i,
+                        format!("skipped `{}`, getters with a body are not fields", &cap[1]),
+                    ));
+                }
+                continue;
+            }
+
+            // checking fields inside class
+            if members && let Some(cap) = FIELD_REGEX.captures(line) {
+                // checking for field options, doc comments and deprecations above the field
+                // options can be spread over consecutive lines
+                let mut option_lines: Vec<String> = vec![];
+                let mut docs: Vec<String> = vec![];
+                let mut deprecations: Vec<String> = vec![];
+                // key of a json_serializable `@JsonKey(name: '...')` annotation
+                let mut json_key: Option<String> = None;
+                for above in lines[..i].iter().rev() {
+                    if above.trim_start().starts_with("///") {
+                        docs.insert(0, above.trim().to_string());
+                    } else if DEPRECATED_REGEX.is_match(above) {
+                        deprecations.insert(0, above.trim().to_string());
+                    } else if let Some(cap) = annotation.field_regex.captures(above) {
+                        option_lines.insert(0, cap[1].to_string());
+                    } else if let Some(cap) = JSON_KEY_REGEX.captures(above) {
+                        json_key = json_key.or(cap.get(1).map(|m| m.as_str().to_string()));
+                    } else {
+                        break;
+                    }
+                }
+                let mut options = FieldOptions::from_string(&option_lines.join(" "));
+                options.coerce |= config.coerce_numbers;
+                // an explicit `key` option takes precedence
+                options.key = options.key.or(json_key);
+                if DART_RESERVED_WORDS.contains(&&cap[2]) {
+                    diagnostics.push(Diagnostic::error(
+                        i,
+                        format!(
+                            "`{}` is a reserved word in Dart, rename the field and use `key=\"{}\"` to keep the JSON key",
+                            &cap[2], &cap[2]
+                        ),
+                    ));
+                    continue;
+                }
+                if options.is_enum_index && options.enum_fallback.is_some() {
+                    diagnostics.push(Diagnostic::warning(
+                        i,
+                        "`fallback` is ignored for enums serialized by `index`".to_string(),
+                    ));
+                }
+                let type_name = resolve_typedefs(&normalize_type(&cap[1]), &typedefs);
+                if !is_well_formed_type(&type_name) {
+                    diagnostics.push(Diagnostic::error(
+                        i,
+                        format!(
+                            "malformed type `{type_name}` of `{}`, check its brackets",
+                            &cap[2]
+                        ),
+                    ));
+                    continue;
+                }
+                if FUNCTION_TYPE_REGEX.is_match(&type_name) && !options.ignore {
+                    diagnostics.push(Diagnostic::warning(
+                        i,
+                        format!(
+                            "skipped `{}`, function fields can't be serialized unless marked with `ignore`",
+                            &cap[2]
+                        ),
+                    ));
+                    continue;
+                }
+                // converted by both functions or not in JSON, the type doesn't matter
+                let converted = options.ignore
+                    || (options.computed.is_some() && !options.serialize)
+                    || (options.from_json_fn.is_some() && options.to_json_fn.is_some());
+                if !converted
+                    && let Some(unserializable) = WORD_REGEX
+                        .find_iter(&type_name)
+                        .map(|m| m.as_str())
+                        .find(|word| {
+                            UNSERIALIZABLE_TYPES.contains(word) && !declared_types.contains(*word)
+                        })
+                {
+                    diagnostics.push(Diagnostic::warning(
+                        i,
+                        format!(
+                            "skipped `{}`, `{unserializable}` can't be serialized, convert it with `fromJson=` and `toJson=` functions or mark it with `ignore`",
+                            &cap[2]
+                        ),
+                    ));
+                    continue;
+                }
+                if classes
+                    .last()
+                    .unwrap()
+                    .fields
+                    .iter()
+                    .any(|f| f.name == cap[2])
+                {
+                    diagnostics.push(Diagnostic::error(
+                        i,
+                        format!("duplicate field `{}`", &cap[2]),
+                    ));
+                    continue;
+                }
+                let type_parameters = classes.last().unwrap().type_parameter_names();
+                let mut typ = DartType::from_string_and_options(
+                    type_name.clone(),
+                    &options,
+                    &enums,
+                    &type_parameters,
+                );
+                if let Some(key) = typ.unsupported_map_key() {
+                    diagnostics.push(Diagnostic::error(
+                        i,
+                        format!("`{key}` map keys can't be decoded from JSON object keys"),
+                    ));
+                    continue;
+                }
+                if options.empty_if_null && !(typ.is_nullable() && typ.is_collection()) {
+                    diagnostics.push(Diagnostic::warning(
+                        i,
+                        "`emptyIfNull` only applies to nullable collections".to_string(),
+                    ));
+                }
+                if options.empty_default {
+                    if typ.is_nullable() && typ.is_collection() {
+                        // a non-nullable field overriding the nullable getter
+                        let non_nullable = type_name.strip_suffix('?').unwrap().to_string();
+                        typ = DartType::from_string_and_options(
+                            non_nullable,
+                            &options,
+                            &enums,
+                            &type_parameters,
+                        );
+                        options.default_value = options.default_value.or(typ.empty_literal());
+                    } else {
+                        diagnostics.push(Diagnostic::warning(
+                            i,
+                            "`emptyDefault` only applies to nullable collections".to_string(),
+                        ));
+                    }
+                }
+                if options.ignore
+                    && options.computed.is_none()
+                    && options.default_value.is_none()
+                    && !typ.is_nullable()
+                {
+                    // `fromJson` would miss a required constructor argument
+                    diagnostics.push(Diagnostic::error(
+                        i,
+                        format!(
+                            "`{}` is not in JSON, `ignore` requires a `default` for non-nullable fields",
+                            &cap[2]
+                        ),
+                    ));
+                    continue;
+                }
+                classes.last_mut().unwrap().fields.push(DartField::new(
+                    cap[2].to_string(),
+                    typ,
+                    options,
+                    docs,
+                    deprecations,
+                ));
+            } else {
+                if members && is_unrecognized_member(line) {
+                    diagnostics.push(Diagnostic::warning(
+                        i,
+                        format!(
+                            "skipped `{}`, fields must be declared as `Type get name;`",
+                            line.trim()
+                        ),
+                    ));
+                }
+                // for skipping method declarations
+                depth += brace_depth_change(code);
+                // a method closed on the class's last line, as in `void f() {}}`
+                if depth <= 0 && class_start {
+                    // end of a @flu class
+                    class_start = false;
+                    annotation_start = false;
+                    diagnostics.extend(classes.last().unwrap().const_hint(class_line, config));
+                }
+            }
+        }
+        // reaching the end of the file inside a class, rather than generating it partially
+        if class_start {
+            let class = classes.pop().unwrap();
+            class_lines.pop();
+            diagnostics.push(Diagnostic::error(
+                class_line,
+                format!(
+                    "unterminated `{}` class `{}`",
+                    annotation.class_marker,
+                    base_name.of(&class.abstract_name)
+                ),
+            ));
+        } else if annotation_start {
+            diagnostics.push(Diagnostic::error(
+                lines.len().saturating_sub(1),
+                format!(
+                    "`{}` at the end of the file is not followed by a class",
+                    annotation.class_marker
+                ),
+            ));
+        }
+        for class in &mut classes {
+            if let Some(name) = class.directives.iter().find_map(|d| match d {
+                ClassDirective::Name(name) => Some(name.clone()),
+                _ => None,
+            }) {
+                class.name = name;
+            }
+        }
+        Self::inherit_fields(&mut classes, &class_lines, base_name, &mut diagnostics);
+        diagnostics.sort_by_key(|d| d.line);
+        DartFile::new(path.to_string(), classes, diagnostics)
+    }
+
+    /// Adds the fields and the `copyWith` declaration of the parent classes to
+    /// the classes extending them, parents can be declared anywhere in the file.
+    ///
+    /// Every generated member follows the resulting order: the fields of the
+    /// root class first, down to the fields of the class, each in declaration
+    /// order. A redeclared field takes the place of its latest declaration.
+    fn inherit_fields(
+        classes: &mut [DartClass],
+        class_lines: &[usize],
+        base_name: &BaseName,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        let declared: Vec<Vec<DartField>> = classes.iter().map(|c| c.fields.clone()).collect();
+        let declares_copy_with: Vec<bool> = classes.iter().map(|c| c.declares_copy_with).collect();
+        let index_of = |name: &str| classes.iter().position(|c| c.abstract_name == name);
+        let mut inherited: Vec<(Vec<DartField>, bool)> = vec![];
+        for (i, class) in classes.iter().enumerate() {
+            // ancestors from the parent to the root
+            let mut ancestors: Vec<usize> = vec![];
+            let mut parent = class.parent.as_deref();
+            while let Some(name) = parent {
+                match index_of(name) {
+                    Some(index) if index == i || ancestors.contains(&index) => {
+                        diagnostics.push(Diagnostic::error(
+                            class_lines[i],
+                            format!(
+                                "`{}` extends itself through `{}`",
+                                base_name.of(&class.abstract_name),
+                                base_name.of(name)
+                            ),
+                        ));
+                        ancestors.clear();
+                        break;
+                    }
+                    Some(index) => {
+                        ancestors.push(index);
+                        parent = classes[index].parent.as_deref();
+                    }
+                    None => {
+                        diagnostics.push(Diagnostic::error(
+                            class_lines[i],
+                            format!(
+                                "parent class `{}` must be declared in the same file",
+                                base_name.of(name)
+                            ),
+                        ));
+                        break;
+                    }
+                }
+            }
+            // fields of the root first, a redeclared field keeps its latest declaration
+            let mut fields: Vec<DartField> = vec![];
+            for (depth, &index) in ancestors.iter().enumerate().rev() {
+                for field in &declared[index] {
+                    let redeclared = ancestors[..depth]
+                        .iter()
+                        .chain([&i])
+                        .any(|&a| declared[a].iter().any(|f| f.name == field.name));
+                    if !redeclared {
+                        fields.push(field.clone());
+                    }
+                }
+            }
+            let copy_with = ancestors.iter().any(|&a| declares_copy_with[a]);
+            inherited.push((fields, copy_with));
+        }
+        for (class, (mut fields, copy_with)) in classes.iter_mut().zip(inherited) {
+            fields.append(&mut class.fields);
+            class.fields = fields;
+            class.declares_copy_with |= copy_with;
+        }
+    }
+
+    /// Logs the diagnostics, returns whether any of them is an error
+    pub fn report(&self, log: &mut String) -> bool {
+        for diagnostic in &self.diagnostics {
+            *log += &format!("{}:{diagnostic}\n", self.path);
+        }
+        self.diagnostics.iter().any(|d| d.level == Level::Error)
+    }
+
+    /// Whether any of the diagnostics is a warning
+    pub fn has_warnings(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.level == Level::Warning)
+    }
+
+    /// Logs the classes found in the file and their field count
+    pub fn log_classes(&self, log: &mut String) {
+        *log += &format!(
+            "{}: info: found {} classes\n",
+            self.path,
+            self.classes.len()
+        );
+        for class in &self.classes {
+            *log += &format!(
+                "{}: info: class `{}` with {} fields\n",
+                self.path,
+                class.name,
+                class.fields.len()
+            );
+        }
+    }
+
+    pub fn generated_path(&self, config: &Config) -> String {
+        let stem = self.path.strip_suffix(".dart").unwrap_or(&self.path);
+        let path = format!("{stem}.{}.dart", config.extension);
+        match &config.output {
+            // mirroring the source path under the output directory
+            Some(output) => output
+                .join(relative_to_current_dir(Path::new(&path)))
+                .to_string_lossy()
+                .to_string(),
+            None => path,
+        }
+    }
+
+    /// Path used in `part of`, relative to the generated file
+    fn part_of_path(&self, config: &Config) -> String {
+        match &config.output {
+            Some(_) => {
+                let generated = normalize_path(Path::new(&self.generated_path(config)));
+                relative_path(
+                    generated.parent().unwrap(),
+                    &normalize_path(Path::new(&self.path)),
+                )
+            }
+            None => self.file_name(),
+        }
+    }
+
+    fn file_name(&self) -> String {
+        Path::new(&self.path)
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Whether the generated file exists with the given content
+    pub fn is_up_to_date(&self, content: &str, config: &Config) -> bool {
+        fs::read_to_string(self.generated_path(config)).is_ok_and(|existing| existing == content)
+    }
+
+    /// Writes the generated file, returns whether it was written
+    pub fn generate_file(&self, config: &Config) -> io::Result<bool> {
+        match self.generate_string(config) {
+            Some(content) => self.write_file(&content, config),
+            None => Ok(false),
+        }
+    }
+
+    /// Writes the given content as the generated file, returns whether it was written
+    pub fn write_file(&self, content: &str, config: &Config) -> io::Result<bool> {
+        // leaving up to date files untouched to keep their modification time
+        if self.is_up_to_date(content, config) {
+            return Ok(false);
+        }
+        let generated_path = self.generated_path(config);
+        if let Some(parent) = Path::new(&generated_path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(generated_path, content)?;
+        Ok(true)
+    }
+
+    /// Content of the generated file, `None` when there is nothing to generate
+    pub fn generate_string(&self, config: &Config) -> Option<String> {
+        if self.classes.is_empty() {
+            return None;
+        }
+        let mut ignores = vec![
+            "avoid_equals_and_hash_code_on_mutable_classes",
+            "document_ignores",
+            "lines_longer_than_80_chars",
+        ];
+        // the generated members still read the deprecated fields
+        if self
+            .classes
+            .iter()
+            .any(|c| c.fields.iter().any(|f| !f.deprecations.is_empty()))
+        {
+            ignores.insert(0, "deprecated_member_use_from_same_package");
+        }
+        let mut lines = vec![format!("// ignore_for_file: {}", ignores.join(", "))];
+        // formatted by `dart format` after generation instead
+        if !config.format_on {
+            lines.insert(0, "// dart format off\n".to_string());
+        }
+        // libraries used by the generated code
+        let mut imports: Vec<&str> = vec![];
+        let configs: Vec<Config> = self.classes.iter().map(|c| c.config(config)).collect();
+        if configs.iter().any(|c| c.equatable) {
+            imports.push("package:equatable/equatable.dart");
+        }
+        if configs.iter().any(|c| c.raw_json) {
+            imports.push("dart:convert");
+        }
+        if configs.iter().any(|c| c.immutable) {
+            imports.push("package:meta/meta.dart");
+        }
+        // collections are compared by value in operator == and hashCode
+        if self
+            .classes
+            .iter()
+            .zip(&configs)
+            .any(|(c, config)| !config.equatable && c.fields.iter().any(|f| f.typ.is_collection()))
+        {
+            imports.push("package:collection/collection.dart");
+        }
+        let nullable_copy_with = self.classes.iter().any(|c| {
+            c.fields.iter().any(|f| {
+                f.typ.is_nullable() && !f.options.skip_copy_with && f.options.computed.is_none()
+            })
+        });
+        if nullable_copy_with && config.copy_with_style == CopyWithStyle::ValueGetter {
+            imports.push("package:flutter/foundation.dart");
+        }
+        if config.standalone {
+            // dart: libraries first, then packages, then the source library
+            imports.sort_by_key(|uri| (!uri.starts_with("dart:"), *uri));
+            let imports: Vec<String> = imports
+                .iter()
+                .map(|uri| format!("import '{uri}';"))
+                .collect();
+            if !imports.is_empty() {
+                lines.push(format!("\n{}", imports.join("\n")));
+            }
+            lines.push(format!("\nimport '{}';", self.part_of_path(config)));
+        } else {
+            lines.push(format!("\npart of '{}';", self.part_of_path(config)));
+            for uri in imports {
+                lines.push(format!("\n// requires `import '{uri}';` in the library"));
+            }
+        }
+        // default value of nullable copyWith parameters, for telling apart
+        // "not provided" from an explicit null
+        if nullable_copy_with && config.copy_with_style == CopyWithStyle::Sentinel {
+            lines.push("\nconst _sentinel = Object();".to_string());
+        }
+        for (class, config) in self.classes.iter().zip(&configs) {
+            // class definition start
+            let mixin = if config.equatable {
+                " with EquatableMixin"
+            } else {
+                ""
+            };
+            let annotation = if config.immutable { "@immutable\n" } else { "" };
+            let extends = if class.standalone {
+                "".to_string()
+            } else {
+                format!(
+                    " extends {}{}",
+                    config.base_name.of(&class.abstract_name),
+                    &class.type_name()[class.name.len()..]
+                )
+            };
+            lines.push(format!(
+                "\n{annotation}class {}{}{extends}{mixin} {{",
+                class.name,
+                class.type_parameters_declaration()
+            ));
+
+            Self::add_constructor(class, config, &mut lines);
+
+            Self::add_from_json(class, config, &mut lines);
+            let cases = self.union_cases(class, &configs);
+            // the base class is private to the source library in standalone mode
+            if !cases.is_empty() && !config.standalone {
+                Self::add_from_json_union(class, config, &cases, &mut lines);
+            }
+            Self::add_from_json_list(class, &mut lines);
+            if config.map_aliases {
+                Self::add_from_map(class, &mut lines);
+            }
+            if config.raw_json {
+                Self::add_from_raw_json(class, &mut lines);
+            }
+
+            Self::add_fields(class, config, &mut lines);
+
+            Self::add_to_json(class, config, &mut lines);
+            if config.map_aliases {
+                Self::add_to_map(class, &mut lines);
+            }
+            if config.raw_json {
+                Self::add_to_raw_json(class, &mut lines);
+            }
+
+            Self::add_copy_with(class, config, &mut lines);
+            if config.nested_copy_with {
+                Self::add_nested_copy_with(class, &mut lines);
+            }
+            if config.merge {
+                Self::add_merge(class, config, &mut lines);
+            }
+            if config.copy_with_map {
+                Self::add_copy_with_map(class, config, &mut lines);
+            }
+
+            Self::add_validate(class, &mut lines);
+
+            Self::add_to_string(class, config, &mut lines);
+
+            if config.equatable {
+                Self::add_props(class, &mut lines);
+            } else {
+                Self::add_equal_operator(class, config, &mut lines);
+
+                Self::add_hash_code(class, config, &mut lines);
+            }
+
+            // class definition end
+            lines.push("}\n".to_string());
+        }
+
+        Some(reindent(
+            &collapse_blank_lines(&lines.join("\n")),
+            config.indent,
+        ))
+    }
+
+    fn add_constructor(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        let const_key =
+            if (class.has_const_constructor || config.const_constructors) && !config.mutable {
+                "const "
+            } else {
+                ""
+            };
+        let initializers: Vec<String> = class
+            .fields
+            .iter()
+            .filter_map(|f| Some(format!("{} = {}", f.name, f.options.computed.as_ref()?)))
+            .collect();
+        let initializers = if initializers.is_empty() {
+            "".to_string()
+        } else {
+            format!(" : {}", initializers.join(", "))
+        };
+        let params = class.constructor_fields();
+        if params.is_empty() {
+            lines.push(format!("  {const_key}{}(){initializers};", class.name));
+        } else if config.positional {
+            // only the trailing fields with a default can be optional,
+            // `fromJson` still applies the defaults of the others
+            let optional = params
+                .iter()
+                .rev()
+                .take_while(|f| f.default_value().is_some())
+                .count();
+            let (required, optional) = params.split_at(params.len() - optional);
+            let mut params: Vec<String> = required
+                .iter()
+                .map(|f| format!("this.{}", f.name))
+                .collect();
+            if !optional.is_empty() {
+                let optional: Vec<String> = optional
+                    .iter()
+                    .map(|f| format!("this.{} = {}", f.name, f.default_value().unwrap()))
+                    .collect();
+                params.push(format!("[{}]", optional.join(", ")));
+            }
+            lines.push(format!(
+                "  {const_key}{}({}){initializers};",
+                class.name,
+                params.join(", ")
+            ));
+        } else {
+            lines.push(format!("  {const_key}{}({{", class.name));
+            for field in params {
+                match field.default_value() {
+                    Some(default) => lines.push(format!("    this.{} = {default},", field.name)),
+                    // defaulting to null
+                    None if config.optional_nullable && field.typ.is_nullable() => {
+                        lines.push(format!("    this.{},", field.name))
+                    }
+                    None => lines.push(format!("    required this.{},", field.name)),
+                }
+            }
+            Self::end_list(config, lines);
+            lines.push(format!("  }}){initializers};"));
+        }
+    }
+
+    fn add_from_json(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        lines.push(format!(
+            "\n  factory {}.fromJson(Map<String, dynamic> json{}) {{",
+            class.name,
+            class.from_json_parameters()
+        ));
+        if let Some((key, value)) = class.discriminator(config) {
+            lines.push(format!("    assert({} == '{value}');", json_access(&[key])));
+        }
+        lines.push(format!("    return {}(", class.name));
+        for field in class.constructor_fields() {
+            let DartField { name, typ, .. } = field;
+            if field.options.ignore {
+                // not in JSON, the constructor default applies when there is one
+                match field.default_value() {
+                    // every preceding positional argument must be given
+                    Some(default) if config.positional => {
+                        lines.push(format!("      {default},"));
+                    }
+                    None if typ.is_nullable() => {
+                        lines.push(format!("      {},", argument(name, "null", config)))
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            let value = field.from_json(class, config);
+            lines.push(format!("      {},", argument(name, &value, config)));
+        }
+        Self::close_on_same_line("(", ");", "    );", config, lines);
+        lines.push("  }".to_string());
+    }
+
+    /// Classes decoded by the `fromJsonUnion` of a class, as (discriminator key,
+    /// value, class name). These are the class and the classes extending it,
+    /// directly or not, that have the discriminator key of the first of them.
+    fn union_cases(&self, base: &DartClass, configs: &[Config]) -> Vec<(String, String, String)> {
+        let mut cases: Vec<(String, String, String)> = vec![];
+        for (class, config) in self.classes.iter().zip(configs) {
+            // generic classes can't be decoded without their converters
+            if !class.type_parameters.is_empty() {
+                continue;
+            }
+            let mut parent = Some(class.abstract_name.as_str());
+            // bounded, as a class extending itself is only reported
+            for _ in 0..=self.classes.len() {
+                if parent == Some(base.abstract_name.as_str()) {
+                    break;
+                }
+                parent = parent
+                    .and_then(|name| self.classes.iter().find(|c| c.abstract_name == name))
+                    .and_then(|c| c.parent.as_deref());
+            }
+            if parent == Some(base.abstract_name.as_str())
+                && let Some((key, value)) = class.discriminator(config)
+            {
+                cases.push((key, value, class.name.clone()));
+            }
+        }
+        // a class extended by nothing decodes with its own `fromJson`
+        if cases.iter().all(|(.., name)| *name == base.name) {
+            return vec![];
+        }
+        let key = cases[0].0.clone();
+        cases.retain(|(k, ..)| *k == key);
+        cases
+    }
+
+    /// Decodes the subclass matching the discriminator of the JSON map,
+    /// throws `ArgumentError` for an unknown value
+    fn add_from_json_union(
+        class: &DartClass,
+        config: &Config,
+        cases: &[(String, String, String)],
+        lines: &mut Vec<String>,
+    ) {
+        let key = json_access(&[cases[0].0.clone()]);
+        lines.push(format!(
+            "
+  static {} fromJsonUnion(Map<String, dynamic> json) => switch ({key}) {{",
+            config.base_name.of(&class.abstract_name)
+        ));
+        for (_, value, name) in cases {
+            lines.push(format!("    '{value}' => {name}.fromJson(json),"));
+        }
+        lines.push(format!(
+            "    _ => throw ArgumentError.value({key}, '{}', 'unknown {} type'),",
+            cases[0].0, class.name
+        ));
+        Self::end_list(config, lines);
+        lines.push("  };".to_string());
+    }
+
+    /// Static methods declare the type parameters of a generic class themselves
+    fn add_from_json_list(class: &DartClass, lines: &mut Vec<String>) {
+        let typ = class.type_name();
+        let (declaration, params, args) = (
+            class.type_parameters_declaration(),
+            class.from_json_parameters(),
+            class.from_json_arguments(),
+        );
+        lines.push(format!(
+            "\n  static List<{typ}> fromJsonList{declaration}(List<dynamic> json{params}) =>\n      json.map((e) => {typ}.fromJson(e as Map<String, dynamic>{args})).toList();"
+        ));
+    }
+
+    fn add_from_map(class: &DartClass, lines: &mut Vec<String>) {
+        let name = &class.name;
+        let (params, args) = (class.from_json_parameters(), class.from_json_arguments());
+        lines.push(format!(
+            "\n  factory {name}.fromMap(Map<String, dynamic> map{params}) => {name}.fromJson(map{args});"
+        ));
+    }
+
+    fn add_from_raw_json(class: &DartClass, lines: &mut Vec<String>) {
+        let (name, typ) = (&class.name, class.type_name());
+        let (declaration, params, args) = (
+            class.type_parameters_declaration(),
+            class.from_json_parameters(),
+            class.from_json_arguments(),
+        );
+        lines.push(format!(
+            "\n  factory {name}.fromRawJson(String str{params}) =>\n      {name}.fromJson(json.decode(str) as Map<String, dynamic>{args});"
+        ));
+        // a JSON array string, decoded by `fromJsonList`
+        lines.push(format!(
+            "\n  static List<{typ}> fromRawJsonList{declaration}(String str{params}) =>\n      fromJsonList(json.decode(str) as List<dynamic>{args});"
+        ));
+    }
+
+    fn add_fields(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        // a mutable field still overrides the getter of the abstract class
+        let final_key = if config.mutable { "" } else { "final " };
+        let override_key = if class.standalone {
+            ""
+        } else {
+            "@override\n  "
+        };
+        for field in &class.fields {
+            lines.push("".to_string());
+            // doc comments come before the annotations, whatever their order above the getter
+            for line in field.docs.iter().chain(&field.deprecations) {
+                lines.push(format!("  {line}"));
+            }
+            let options = &field.options;
+            let in_json = !options.ignore && (options.computed.is_none() || options.serialize);
+            let key_comment = if config.annotate_keys && in_json {
+                format!(" // json: '{}'", field.json_key(config.key_case))
+            } else {
+                "".to_string()
+            };
+            lines.push(format!(
+                "  {override_key}{final_key}{} {};{key_comment}",
+                field.typ.type_string(),
+                field.name
+            ));
+        }
+    }
+
+    fn add_to_json(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        lines.push(format!(
+            "\n  Map<String, dynamic> toJson({}) => {{",
+            class.to_json_parameters()
+        ));
+        // (key path, condition, value) of the entries
+        let mut entries: Vec<(Vec<String>, String, String)> = vec![];
+        if let Some((key, value)) = class.discriminator(config) {
+            entries.push((vec![key], "".to_string(), format!("'{value}'")));
+        }
+        for field in &class.fields {
+            let DartField {
+                name, typ, options, ..
+            } = field;
+            if options.ignore || (options.computed.is_some() && !options.serialize) {
+                continue;
+            }
+            let path = field.json_path(config.key_case);
+            let value = match &options.to_json_fn {
+                Some(function) => format!("{function}({name})"),
+                None => typ.to_json_value(name.to_string(), options, false),
+            };
+            let condition = if typ.is_nullable() && (config.omit_nulls || options.omit_if_null) {
+                format!("if ({name} != null) ")
+            } else {
+                "".to_string()
+            };
+            entries.push((path, condition, value));
+        }
+        Self::add_json_entries(&entries, 2, config, lines);
+        Self::close_on_same_line("{", "};", "  };", config, lines);
+    }
+
+    /// Closes an empty parameter list or literal on its opening line, as in
+    /// `return Empty();`, instead of leaving it spread over two lines
+    fn close_on_same_line(
+        open: &str,
+        close: &str,
+        close_line: &str,
+        config: &Config,
+        lines: &mut Vec<String>,
+    ) {
+        match lines.last_mut() {
+            Some(last) if last.ends_with(open) => last.push_str(close),
+            _ => {
+                Self::end_list(config, lines);
+                lines.push(close_line.to_string())
+            }
+        }
+    }
+
+    /// Removes the trailing comma of the last element of a multi-line list
+    /// with `--trailing-comma never`, each element being on its own line
+    fn end_list(config: &Config, lines: &mut [String]) {
+        if config.trailing_comma == TrailingComma::Never
+            && let Some(last) = lines.last_mut()
+            && last.ends_with(',')
+        {
+            last.pop();
+        }
+    }
+
+    /// Adds map entries, grouping the entries of a key path in nested maps
+    fn add_json_entries(
+        entries: &[(Vec<String>, String, String)],
+        level: usize,
+        config: &Config,
+        lines: &mut Vec<String>,
+    ) {
+        let indent = indent(level, 2);
+        let mut nested: Vec<&str> = vec![];
+        for (path, condition, value) in entries {
+            if path.len() == 1 {
+                lines.push(format!(
+                    "{indent}{condition}{}: {value},",
+                    dart_string(&path[0])
+                ));
+                continue;
+            }
+            if nested.contains(&path[0].as_str()) {
+                continue;
+            }
+            nested.push(&path[0]);
+            let children: Vec<(Vec<String>, String, String)> = entries
+                .iter()
+                .filter(|(p, ..)| p.len() > 1 && p[0] == path[0])
+                .map(|(p, c, v)| (p[1..].to_vec(), c.clone(), v.clone()))
+                .collect();
+            lines.push(format!("{indent}{}: {{", dart_string(&path[0])));
+            Self::add_json_entries(&children, level + 1, config, lines);
+            Self::end_list(config, lines);
+            lines.push(format!("{indent}}},"));
+        }
+    }
+
+    fn add_to_map(class: &DartClass, lines: &mut Vec<String>) {
+        let (params, args) = (class.to_json_parameters(), class.to_json_arguments());
+        lines.push(format!(
+            "\n  Map<String, dynamic> toMap({params}) => toJson({args});"
+        ));
+    }
+
+    fn add_to_raw_json(class: &DartClass, lines: &mut Vec<String>) {
+        let (params, args) = (class.to_json_parameters(), class.to_json_arguments());
+        lines.push(format!(
+            "\n  String toRawJson({params}) => json.encode(toJson({args}));"
+        ));
+    }
+
+    /// The parameters stay named with a positional constructor
+    fn add_copy_with(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        let fields = class.constructor_fields();
+        let params: Vec<&DartField> = fields
+            .iter()
+            .copied()
+            .filter(|f| !f.options.skip_copy_with)
+            .collect();
+        // always returning the generated class, a subtype of any declared return type
+        let start = if class.declares_copy_with {
+            "\n  @override\n  "
+        } else {
+            "\n  "
+        };
+        if fields.is_empty() {
+            lines.push(format!(
+                "{start}{} copyWith() => {}();",
+                class.type_name(),
+                class.name
+            ));
+            return;
+        }
+        if params.is_empty() {
+            lines.push(format!(
+                "{start}{} copyWith() => {}(",
+                class.type_name(),
+                class.name
+            ));
+        } else {
+            lines.push(format!("{start}{} copyWith({{", class.type_name()));
+            for DartField { name, typ, .. } in &params {
+                if typ.is_nullable() {
+                    match config.copy_with_style {
+                        CopyWithStyle::Sentinel => {
+                            lines.push(format!("    Object? {name} = _sentinel,"))
+                        }
+                        CopyWithStyle::ValueGetter => {
+                            lines.push(format!("    ValueGetter<{}>? {name},", typ.type_string()))
+                        }
+                        CopyWithStyle::Plain => {
+                            lines.push(format!("    {} {name},", typ.type_string()))
+                        }
+                    }
+                    continue;
+                }
+                // no ? for dynamic
+                let null_mark = if typ.is_dynamic() { "" } else { "?" };
+                lines.push(format!(
+                    "    {}{null_mark} {name},",
+                    typ.non_null_type_string()
+                ));
+            }
+            Self::end_list(config, lines);
+            lines.push(format!("  }}) => {}(", class.name));
+        }
+        for DartField {
+            name, typ, options, ..
+        } in fields
+        {
+            let value = if options.skip_copy_with {
+                format!("this.{name}")
+            } else if typ.is_nullable() {
+                match config.copy_with_style {
+                    CopyWithStyle::Sentinel => format!(
+                        "identical({name}, _sentinel) ? this.{name} : {name} as {}",
+                        typ.type_string()
+                    ),
+                    CopyWithStyle::ValueGetter => {
+                        format!("{name} != null ? {name}() : this.{name}")
+                    }
+                    CopyWithStyle::Plain => format!("{name} ?? this.{name}"),
+                }
+            } else {
+                format!("{name} ?? this.{name}")
+            };
+            lines.push(format!("    {},", argument(name, &value, config)));
+        }
+        Self::end_list(config, lines);
+        lines.push("  );".to_string());
+    }
+
+    /// The nested class may be declared in another file, so its fields are unknown
+    /// and the update is a function instead of `copyWith` parameters
+    fn add_nested_copy_with(class: &DartClass, lines: &mut Vec<String>) {
+        for DartField {
+            name, typ, options, ..
+        } in &class.fields
+        {
+            let DartType::Concrete(Concrete {
+                typ: ConcreteType::Custom(custom),
+                nullable: false,
+            }) = typ
+            else {
+                continue;
+            };
+            if options.skip_copy_with || options.computed.is_some() {
+                continue;
+            }
+            let method = format!("copyWith{}", KeyCase::Pascal.apply(name));
+            lines.push(format!(
+                "\n  {} {method}({custom} Function({custom} {name}) update) =>\n      copyWith({name}: update({name}));",
+                class.name
+            ));
+        }
+    }
+
+    /// Fields excluded from `copyWith` keep their value
+    fn add_merge(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        let (name, typ) = (&class.name, class.type_name());
+        let fields = class.constructor_fields();
+        if fields.is_empty() {
+            lines.push(format!("\n  {typ} merge({typ} other) => {name}();"));
+            return;
+        }
+        lines.push(format!("\n  {typ} merge({typ} other) => {name}("));
+        for DartField {
+            name, typ, options, ..
+        } in fields
+        {
+            let value = if options.skip_copy_with {
+                format!("this.{name}")
+            } else if typ.is_nullable() {
+                format!("other.{name} ?? this.{name}")
+            } else {
+                format!("other.{name}")
+            };
+            lines.push(format!("    {},", argument(name, &value, config)));
+        }
+        Self::end_list(config, lines);
+        lines.push("  );".to_string());
+    }
+
+    /// Decodes the fields whose key is in the map, as `fromJson` does, and
+    /// keeps the current value of the others
+    fn add_copy_with_map(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        let (name, typ) = (&class.name, class.type_name());
+        let params = class.from_json_parameters();
+        let fields = class.constructor_fields();
+        if fields.is_empty() {
+            lines.push(format!(
+                "\n  {typ} copyWithMap(Map<String, dynamic> json{params}) => {name}();"
+            ));
+            return;
+        }
+        lines.push(format!(
+            "\n  {typ} copyWithMap(Map<String, dynamic> json{params}) => {name}("
+        ));
+        for field in fields {
+            let name = &field.name;
+            let value = if field.options.skip_copy_with || field.options.ignore {
+                format!("this.{name}")
+            } else {
+                let mut decoded = field.from_json(class, config);
+                // a nested conditional, as with a default value
+                if decoded.contains(" ? ") {
+                    decoded = format!("({decoded})");
+                }
+                format!(
+                    "{} ? {decoded} : this.{name}",
+                    json_contains(&field.json_path(config.key_case)),
+                )
+            };
+            lines.push(format!("    {},", argument(name, &value, config)));
+        }
+        Self::end_list(config, lines);
+        lines.push("  );".to_string());
+    }
+
+    /// Throws `ArgumentError` for values breaking the constraints of the field options,
+    /// only for classes with constrained fields
+    fn add_validate(class: &DartClass, lines: &mut Vec<String>) {
+        let constrained: Vec<&DartField> = class
+            .fields
+            .iter()
+            .filter(|f| f.options.has_constraints())
+            .collect();
+        if constrained.is_empty() {
+            return;
+        }
+        lines.push("\n  void validate() {".to_string());
+        for DartField {
+            name, typ, options, ..
+        } in constrained
+        {
+            // null values are not checked
+            let (guard, value) = if typ.is_nullable() {
+                (format!("{name} != null && "), format!("{name}!"))
+            } else {
+                ("".to_string(), name.to_string())
+            };
+            let mut checks: Vec<(String, String)> = vec![];
+            if let Some(min) = &options.min {
+                checks.push((
+                    format!("{value} < {min}"),
+                    format!("must be at least {min}"),
+                ));
+            }
+            if let Some(max) = &options.max {
+                checks.push((format!("{value} > {max}"), format!("must be at most {max}")));
+            }
+            if let Some(min) = &options.min_length {
+                checks.push((
+                    format!("{value}.length < {min}"),
+                    format!("must have a length of at least {min}"),
+                ));
+            }
+            if let Some(max) = &options.max_length {
+                checks.push((
+                    format!("{value}.length > {max}"),
+                    format!("must have a length of at most {max}"),
+                ));
+            }
+            if options.not_empty {
+                checks.push((format!("{value}.isEmpty"), "must not be empty".to_string()));
+            }
+            for (condition, message) in checks {
+                lines.push(format!(
+                    "    if ({guard}{condition}) throw ArgumentError.value({name}, '{name}', '{message}');"
+                ));
+            }
+        }
+        lines.push("  }".to_string());
+    }
+
+    fn add_to_string(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        let name = if config.runtime_type_tostring {
+            "$runtimeType"
+        } else {
+            &class.name
+        };
+        if class.fields.is_empty() {
+            lines.push(format!("\n  @override\n  String toString() => '{name}()';"));
+            return;
+        }
+        lines.push(format!("\n  @override\n  String toString() => '{name}('"));
+        for DartField { name, .. } in &class.fields {
+            lines.push(format!("    '{name}: ${name} '",));
+        }
+        lines.push("    ')';".to_string());
+    }
+
+    fn add_equal_operator(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        lines.push("\n  @override\n  bool operator ==(Object other) {".to_string());
+        lines.push("    if (identical(this, other)) return true;".to_string());
+        if config.exact_type_equals {
+            // `is` is still needed below to promote `other` for the field access
+            lines.push("    if (other.runtimeType != runtimeType) return false;".to_string());
+        }
+        let fields = class.equality_fields();
+        if fields.is_empty() {
+            lines.push(format!("    return other is {};", class.type_name()));
+        } else {
+            lines.push(format!("    return other is {}", class.type_name()));
+            let mut equals = vec![];
+            for DartField { name, typ, .. } in fields {
+                let equal = if typ.is_collection() {
+                    format!("const DeepCollectionEquality().equals(other.{name}, {name})")
+                } else {
+                    format!("other.{name} == {name}")
+                };
+                equals.push(format!("      && {equal}"));
+            }
+            lines.push(equals.join("\n") + ";");
+        }
+        lines.push("  }".to_string());
+    }
+
+    fn add_props(class: &DartClass, lines: &mut Vec<String>) {
+        let props: Vec<&str> = class
+            .equality_fields()
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        lines.push(format!(
+            "\n  @override\n  List<Object?> get props => [{}];",
+            props.join(", ")
+        ));
+    }
+
+    fn add_hash_code(class: &DartClass, config: &Config, lines: &mut Vec<String>) {
+        lines.push("\n  @override".to_string());
+        let fields = class.equality_fields();
+        let values: Vec<String> = fields
+            .iter()
+            .map(|DartField { name, typ, .. }| {
+                if typ.is_collection() {
+                    format!("const DeepCollectionEquality().hash({name})")
+                } else {
+                    name.to_string()
+                }
+            })
+            .collect();
+        match values.len() {
+            // all instances are equal
+            0 => lines.push("  int get hashCode => runtimeType.hashCode;".to_string()),
+            1 if fields[0].typ.is_collection() => {
+                lines.push(format!("  int get hashCode => {};", values[0]))
+            }
+            1 => lines.push(format!("  int get hashCode => {}.hashCode;", values[0])),
+            // `Object.hash` takes 2 to 20 values
+            2..=20 => {
+                lines.push("  int get hashCode => Object.hash(".to_string());
+                for value in values {
+                    lines.push(format!("    {value},"));
+                }
+                Self::end_list(config, lines);
+                lines.push("  );".to_string());
+            }
+            _ => {
+                lines.push("  int get hashCode => Object.hashAll([".to_string());
+                for value in values {
+                    lines.push(format!("    {value},"));
+                }
+                Self::end_list(config, lines);
+                lines.push("  ]);".to_string());
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DartClass {
+    /// Name of the generated class, set by the `name` directive
+    name: String,
+    /// Name of the abstract class, without the base prefix and suffix
+    abstract_name: String,
+    /// Name of the extended class, without the base prefix and suffix
+    parent: Option<String>,
+    has_const_constructor: bool,
+    /// Whether the abstract class or one of its parents declares `copyWith`
+    declares_copy_with: bool,
+    /// Generated extending nothing, for a `class Name {` declaration generating
+    /// `NameData` or in standalone mode
+    standalone: bool,
+    /// Declared type parameters, as `T` or `T extends Object`
+    type_parameters: Vec<String>,
+    /// Inherited fields first, see `DartFile::inherit_fields`
+    fields: Vec<DartField>,
+    directives: Vec<ClassDirective>,
+}
+impl DartClass {
+    fn new(
+        name: String,
+        parent: Option<String>,
+        has_const_constructor: bool,
+        fields: Vec<DartField>,
+        directives: Vec<ClassDirective>,
+    ) -> Self {
+        Self {
+            abstract_name: name.clone(),
+            name,
+            parent,
+            has_const_constructor,
+            declares_copy_with: false,
+            standalone: false,
+            type_parameters: vec![],
+            fields,
+            directives,
+        }
+    }
+
+    fn type_parameter_names(&self) -> Vec<String> {
+        self.type_parameters
+            .iter()
+            .filter_map(|p| p.split_whitespace().next())
+            .map(String::from)
+            .collect()
+    }
+
+    /// Type parameters with their bounds, as in `<T extends Object>`
+    fn type_parameters_declaration(&self) -> String {
+        if self.type_parameters.is_empty() {
+            return "".to_string();
+        }
+        format!("<{}>", self.type_parameters.join(", "))
+    }
+
+    /// Generated class as a type, as in `Box<T>`
+    fn type_name(&self) -> String {
+        let names = self.type_parameter_names();
+        if names.is_empty() {
+            return self.name.clone();
+        }
+        format!("{}<{}>", self.name, names.join(", "))
+    }
+
+    /// Functions decoding the values of the type parameters, following the
+    /// parameters of `fromJson`, as in `, T Function(Object?) fromJsonT`
+    #[allow(clippy::wrong_self_convention)]
+    fn from_json_parameters(&self) -> String {
+        self.type_parameter_names()
+            .iter()
+            .map(|p| format!(", {p} Function(Object?) fromJson{p}"))
+            .collect()
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_json_arguments(&self) -> String {
+        self.type_parameter_names()
+            .iter()
+            .map(|p| format!(", fromJson{p}"))
+            .collect()
+    }
+
+    /// Functions encoding the values of the type parameters, the parameters of `toJson`
+    fn to_json_parameters(&self) -> String {
+        self.type_parameter_names()
+            .iter()
+            .map(|p| format!("Object? Function({p}) toJson{p}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn to_json_arguments(&self) -> String {
+        self.type_parameter_names()
+            .iter()
+            .map(|p| format!("toJson{p}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Fields set through the constructor parameters, all but the computed ones
+    fn constructor_fields(&self) -> Vec<&DartField> {
+        self.fields
+            .iter()
+            .filter(|f| f.options.computed.is_none())
+            .collect()
+    }
+
+    /// Key and value of the type entry in JSON
+    fn discriminator(&self, config: &Config) -> Option<(String, String)> {
+        let (key, value) = config.discriminator.clone()?;
+        Some((key, value.unwrap_or_else(|| self.name.clone())))
+    }
+
+    /// Fields compared by `==` and in `hashCode`, all but the ones set with `equalsIgnore`
+    fn equality_fields(&self) -> Vec<&DartField> {
+        self.fields
+            .iter()
+            .filter(|f| !f.options.equals_ignore)
+            .collect()
+    }
+
+    /// Options of the generated code for this class, with its directives applied
+    fn config(&self, config: &Config) -> Config {
+        let mut config = config.clone();
+        for directive in &self.directives {
+            match directive {
+                ClassDirective::Const => config.const_constructors = true,
+                ClassDirective::Equatable => config.equatable = true,
+                ClassDirective::Immutable => config.immutable = true,
+                ClassDirective::Merge => config.merge = true,
+                ClassDirective::MapAliases => config.map_aliases = true,
+                ClassDirective::RawJson => config.raw_json = true,
+                ClassDirective::Mutable => config.mutable = true,
+                ClassDirective::Positional => config.positional = true,
+                ClassDirective::Discriminator { key, value } => {
+                    config.discriminator = Some((key.clone(), value.clone()))
+                }
+                ClassDirective::Name(_) => {}
+            }
+        }
+        if config.mutable {
+            // a const constructor or `@immutable` requires final fields
+            config.const_constructors = false;
+            config.immutable = false;
+        }
+        config
+    }
+
+    /// Suggests declaring a const constructor when it is missing, the generated
+    /// fields are `final` so every class but the mutable ones can have one
+    fn const_hint(&self, line: usize, config: &Config) -> Option<Diagnostic> {
+        if self.config(config).mutable {
+            // the declared one is kept, only the generated constructor is not const
+            return self.has_const_constructor.then(|| {
+                Diagnostic::warning(
+                    line,
+                    format!(
+                        "`const {}();` is ignored, the generated constructor of a mutable class is not const",
+                        config.base_name.of(&self.abstract_name)
+                    ),
+                )
+            });
+        }
+        if self.has_const_constructor {
+            return None;
+        }
+        if self.config(config).const_constructors {
+            // the generated const constructor can't call a non-const super constructor
+            return Some(Diagnostic::warning(
+                line,
+                format!(
+                    "a const constructor requires `const {}();` in the class",
+                    config.base_name.of(&self.abstract_name)
+                ),
+            ));
+        }
+        Some(Diagnostic::hint(
+            line,
+            format!(
+                "add `const {}();` to the class to generate a const constructor",
+                config.base_name.of(&self.abstract_name)
+            ),
+        ))
+    }
+}
+
+/// `// @flu: <name>` line in or above a class, enabling an option for this class
+#[derive(Debug, Clone)]
+pub enum ClassDirective {
+    /// `const`, as `--const`
+    Const,
+    /// `equatable`, as `--equatable`
+    Equatable,
+    /// `immutable`, as `--immutable`
+    Immutable,
+    /// `merge`, as `--merge`
+    Merge,
+    /// `mapAliases`, as `--map-aliases`
+    MapAliases,
+    /// `rawJson`, as `--raw-json`
+    RawJson,
+    /// `mutable`, non-final fields and a non-const constructor, the equality
+    /// still compares the current field values
+    Mutable,
+    /// `positional`, positional constructor parameters, optional for the
+    /// trailing fields with a default
+    Positional,
+    /// `discriminator=<key>` or `discriminator=<key>:<value>`, a JSON entry
+    /// with the class name or the given value
+    Discriminator { key: String, value: Option<String> },
+    /// `name=<Name>`, the name of the generated class instead of the name of
+    /// the abstract class without its prefix and suffix
+    Name(String),
+}
+impl ClassDirective {
+    fn from_str(name: &str) -> Option<Self> {
+        match name {
+            "const" => Some(Self::Const),
+            "equatable" => Some(Self::Equatable),
+            "immutable" => Some(Self::Immutable),
+            "merge" => Some(Self::Merge),
+            "mapAliases" => Some(Self::MapAliases),
+            "rawJson" => Some(Self::RawJson),
+            "mutable" => Some(Self::Mutable),
+            "positional" => Some(Self::Positional),
+            _ if name.starts_with("name=") => {
+                let name = &name["name=".len()..];
+                (!name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_'))
+                    .then(|| Self::Name(name.to_string()))
+            }
+            _ => {
+                let discriminator = name.strip_prefix("discriminator=")?;
+                let (key, value) = match discriminator.split_once(':') {
+                    Some((key, value)) => (key, Some(value.to_string())),
+                    None => (discriminator, None),
+                };
+                (!key.is_empty()).then(|| Self::Discriminator {
+                    key: key.to_string(),
+                    value,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DartField {
+    name: String,
+    typ: DartType,
+    options: FieldOptions,
+    /// `///` doc comment lines of the getter
+    docs: Vec<String>,
+    /// `@Deprecated(...)` annotations of the getter, kept to warn at call sites
+    deprecations: Vec<String>,
+}
+impl DartField {
+    fn new(
+        name: String,
+        typ: DartType,
+        options: FieldOptions,
+        docs: Vec<String>,
+        deprecations: Vec<String>,
+    ) -> Self {
+        Self {
+            name,
+            typ,
+            options,
+            docs,
+            deprecations,
+        }
+    }
+
+    /// Decodes the field from the `json` map
+    #[allow(clippy::wrong_self_convention)]
+    fn from_json(&self, class: &DartClass, config: &Config) -> String {
+        let typ = &self.typ;
+        let json_value = json_access(&self.json_path(config.key_case));
+        let mut value = match &self.options.from_json_fn {
+            Some(function) => format!("{function}({json_value})"),
+            None => typ.from_json_value(json_value.clone(), &self.options),
+        };
+        if let Some(default) = self.default_value() {
+            value = format!("{json_value} == null ? {default} : {value}");
+        } else if config.assert_required
+            && !typ.is_nullable()
+            && !typ.is_dynamic()
+            && self.options.from_json_fn.is_none()
+        {
+            // clearer than the `TypeError` of casting null
+            value = format!(
+                "{json_value} == null ? throw const FormatException('Missing required field: {} in {}') : {value}",
+                self.json_key(config.key_case),
+                class.name
+            );
+        } else if self.options.empty_if_null
+            && typ.is_nullable()
+            && let Some(empty) = typ.empty_literal()
+        {
+            value = format!("{value} ?? {empty}");
+        }
+        value
+    }
+
+    /// An explicit `key` option always wins over the global key case
+    fn json_key(&self, key_case: KeyCase) -> String {
+        match &self.options.key {
+            Some(k) => k.clone(),
+            None => self.options.key_case.unwrap_or(key_case).apply(&self.name),
+        }
+    }
+
+    /// Keys of the nested maps holding the value, a single key unless set by `path`
+    fn json_path(&self, key_case: KeyCase) -> Vec<String> {
+        let key = self.json_key(key_case);
+        if self.options.is_path {
+            key.split('.').map(String::from).collect()
+        } else {
+            vec![key]
+        }
+    }
+
+    fn default_value(&self) -> Option<&str> {
+        self.options.default_value.as_deref()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ConcreteType {
+    Int,
+    Double,
+    Num,
+    BigInt,
+    Bool,
+    String,
+    Dynamic,
+    /// Passed through like `dynamic`, but keeping the static type and its nullability
+    Object,
+    Enum(String),
+    DateTime,
+    Duration,
+    Uri,
+    Custom(String),
+    /// Type parameter of a generic class, converted by the functions given
+    /// to `fromJson` and `toJson`, as in `fromJsonT` and `toJsonT`
+    TypeParameter(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Concrete {
+    typ: ConcreteType,
+    nullable: bool,
+}
+impl Concrete {
+    fn new(typ: ConcreteType, nullable: bool) -> Self {
+        Self { typ, nullable }
+    }
+
+    fn from_string(name: &str) -> Self {
+        let nullable = name.ends_with('?');
+        match name.replace("?", "").as_str() {
+            "int" => Self::new(ConcreteType::Int, nullable),
+            "double" => Self::new(ConcreteType::Double, nullable),
+            "num" => Self::new(ConcreteType::Num, nullable),
+            "BigInt" => Self::new(ConcreteType::BigInt, nullable),
+            "bool" => Self::new(ConcreteType::Bool, nullable),
+            "dynamic" => Self::new(ConcreteType::Dynamic, false),
+            "Object" => Self::new(ConcreteType::Object, nullable),
+            "String" => Self::new(ConcreteType::String, nullable),
+            "DateTime" => Self::new(ConcreteType::DateTime, nullable),
+            "Duration" => Self::new(ConcreteType::Duration, nullable),
+            "Uri" => Self::new(ConcreteType::Uri, nullable),
+            custom => Self::new(ConcreteType::Custom(custom.to_string()), nullable),
+        }
+    }
+
+    fn type_string(&self) -> String {
+        let null_mark = if self.nullable && !matches!(self.typ, ConcreteType::Dynamic) {
+            "?"
+        } else {
+            ""
+        };
+        (match &self.typ {
+            ConcreteType::Int => "int".to_string(),
+            ConcreteType::Double => "double".to_string(),
+            ConcreteType::Num => "num".to_string(),
+            ConcreteType::BigInt => "BigInt".to_string(),
+            ConcreteType::Bool => "bool".to_string(),
+            ConcreteType::String => "String".to_string(),
+            ConcreteType::Dynamic => "dynamic".to_string(),
+            ConcreteType::Object => "Object".to_string(),
+            ConcreteType::Enum(name) => name.to_string(),
+            ConcreteType::DateTime => "DateTime".to_string(),
+            ConcreteType::Duration => "Duration".to_string(),
+            ConcreteType::Uri => "Uri".to_string(),
+            ConcreteType::Custom(name) | ConcreteType::TypeParameter(name) => name.clone(),
+        }) + null_mark
+    }
+
+    fn non_null_type_string(&self) -> String {
+        if self.nullable {
+            self.type_string().replace("?", "")
+        } else {
+            self.type_string()
+        }
+    }
+
+    fn is_custom(&self) -> bool {
+        matches!(self.typ, ConcreteType::Custom(_))
+    }
+
+    /// Whether the value must be converted before it can be put in a JSON map
+    fn needs_json_conversion(&self) -> bool {
+        self.is_custom()
+            || matches!(
+                self.typ,
+                ConcreteType::TypeParameter(_)
+                    | ConcreteType::DateTime
+                    | ConcreteType::Duration
+                    | ConcreteType::Uri
+                    | ConcreteType::Enum(_)
+                    | ConcreteType::BigInt
+            )
+    }
+
+    /// Decodes a map key from a JSON object key, `None` when it can't be parsed
+    /// from a string. Custom key types are expected to be enums.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_json_key(&self, key: &str) -> Option<String> {
+        if self.nullable
+            && !matches!(
+                self.typ,
+                ConcreteType::String | ConcreteType::Dynamic | ConcreteType::Object
+            )
+        {
+            return None;
+        }
+        match &self.typ {
+            ConcreteType::String | ConcreteType::Dynamic | ConcreteType::Object => {
+                Some(key.to_string())
+            }
+            ConcreteType::Int => Some(format!("int.parse({key})")),
+            ConcreteType::Double => Some(format!("double.parse({key})")),
+            ConcreteType::Num => Some(format!("num.parse({key})")),
+            ConcreteType::BigInt => Some(format!("BigInt.parse({key})")),
+            ConcreteType::DateTime => Some(format!("DateTime.parse({key})")),
+            ConcreteType::Uri => Some(format!("Uri.parse({key})")),
+            ConcreteType::Enum(name) => Some(format!("{name}.values.byName({key})")),
+            // a class has no string form, an enum of another file needs the `enum` option
+            ConcreteType::Bool
+            | ConcreteType::Duration
+            | ConcreteType::Custom(_)
+            | ConcreteType::TypeParameter(_) => None,
+        }
+    }
+
+    /// Encodes a map key as a JSON object key, the reverse of `from_json_key`
+    fn to_json_key(&self, key: &str) -> String {
+        match &self.typ {
+            ConcreteType::String | ConcreteType::Dynamic => key.to_string(),
+            ConcreteType::DateTime => format!("{key}.toIso8601String()"),
+            ConcreteType::Enum(_) => format!("{key}.name"),
+            _ => format!("{key}.toString()"),
+        }
+    }
+
+    fn is_string_key(&self) -> bool {
+        matches!(self.typ, ConcreteType::String | ConcreteType::Dynamic)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_json_value(&self, key: String, options: &FieldOptions) -> String {
+        if self.is_custom() {
+            let name = self.non_null_type_string();
+            let constructor = options.factory.as_deref().unwrap_or("fromJson");
+            let mut factory = format!("{name}.{constructor}({key} as Map<String, dynamic>)");
+            if options.lenient {
+                factory = format!("{key} is {name} ? {key} as {name} : {factory}");
+            }
+            if self.nullable {
+                return format!("{key} == null ? null : {factory}");
+            }
+            return factory;
+        }
+        if let ConcreteType::TypeParameter(name) = &self.typ {
+            if self.nullable {
+                return format!("{key} == null ? null : fromJson{name}({key})");
+            }
+            return format!("fromJson{name}({key})");
+        }
+
+        let null_mark = if self.nullable { "?" } else { "" };
+        let null_guard = if self.nullable {
+            format!("{key} == null ? null : ")
+        } else {
+            "".to_string()
+        };
+        match &self.typ {
+            // numbers and numeric strings, as `42` and `"42"`
+            ConcreteType::Int if options.coerce && options.strict_int => {
+                format!(
+                    "{null_guard}{}",
+                    integral(&format!("num.parse({key}.toString())"))
+                )
+            }
+            ConcreteType::Int if options.coerce => {
+                format!("{null_guard}num.parse({key}.toString()).toInt()")
+            }
+            ConcreteType::Double if options.coerce => {
+                format!("{null_guard}num.parse({key}.toString()).toDouble()")
+            }
+            ConcreteType::Num if options.coerce => {
+                format!("{null_guard}num.parse({key}.toString())")
+            }
+            ConcreteType::Int if options.strict_int => {
+                format!("{null_guard}{}", integral(&format!("{key} as num")))
+            }
+            ConcreteType::Int => format!("({key} as num{null_mark}){null_mark}.toInt()"),
+            ConcreteType::Double => format!("({key} as num{null_mark}){null_mark}.toDouble()"),
+            ConcreteType::Enum(name) if options.is_enum_index => {
+                format!("{null_guard}{name}.values[{key} as int]")
+            }
+            ConcreteType::Enum(name) => {
+                let or_else = match &options.enum_fallback {
+                    Some(fallback) => format!(", orElse: () => {name}.{fallback}"),
+                    None => "".to_string(),
+                };
+                let value = match options.enum_values_map(name) {
+                    Some(map) => format!("({map}[v] ?? v.name)"),
+                    None => "v.name".to_string(),
+                };
+                format!(
+                    "{null_guard}{name}.values.singleWhere((v) => {value} == {key} as String{or_else})"
+                )
+            }
+            ConcreteType::Dynamic => key,
+            ConcreteType::Object if self.nullable => key,
+            ConcreteType::BigInt => format!("{null_guard}BigInt.parse({key} as String)"),
+            ConcreteType::Uri => format!("{null_guard}Uri.parse({key} as String)"),
+            ConcreteType::Duration => match options.duration {
+                DurationUnit::Microseconds => {
+                    format!("{null_guard}Duration(microseconds: {key} as int)")
+                }
+                DurationUnit::Milliseconds => {
+                    format!("{null_guard}Duration(milliseconds: {key} as int)")
+                }
+            },
+            ConcreteType::DateTime => format!(
+                "{null_guard}{}",
+                match options.datetime {
+                    DateTimeFormat::Iso8601 => format!("DateTime.parse({key} as String)"),
+                    DateTimeFormat::EpochMillis => {
+                        format!("DateTime.fromMillisecondsSinceEpoch({key} as int)")
+                    }
+                    DateTimeFormat::EpochSeconds => {
+                        format!("DateTime.fromMillisecondsSinceEpoch(({key} as int) * 1000)")
+                    }
+                }
+            ),
+            ConcreteType::Num
+            | ConcreteType::Bool
+            | ConcreteType::String
+            | ConcreteType::Object
+            | ConcreteType::Custom(_)
+            | ConcreteType::TypeParameter(_) => {
+                format!("{key} as {}", self.type_string())
+            }
+        }
+    }
+
+    /// Encodes the value of `key`, `promoted` when the value is a local variable
+    /// promoted to non-nullable by a null check, as the parameter of a mapper
+    fn to_json_value(&self, key: String, options: &FieldOptions, promoted: bool) -> String {
+        let null_mark = if self.nullable { "?" } else { "" };
+        // the value after a null check
+        let non_null = if promoted {
+            key.clone()
+        } else {
+            format!("{key}!")
+        };
+        match &self.typ {
+            ConcreteType::Int
+            | ConcreteType::Double
+            | ConcreteType::Num
+            | ConcreteType::Bool
+            | ConcreteType::Dynamic
+            | ConcreteType::Object
+            | ConcreteType::String => key,
+            ConcreteType::Enum(_) if options.is_enum_index => format!("{key}{null_mark}.index"),
+            ConcreteType::Enum(name) => match options.enum_values_map(name) {
+                Some(map) if self.nullable => {
+                    format!("{key} == null ? null : {map}[{non_null}] ?? {non_null}.name")
+                }
+                Some(map) => format!("{map}[{key}] ?? {key}.name"),
+                None => format!("{key}{null_mark}.name"),
+            },
+            ConcreteType::BigInt | ConcreteType::Uri => format!("{key}{null_mark}.toString()"),
+            ConcreteType::Duration => match options.duration {
+                DurationUnit::Microseconds => format!("{key}{null_mark}.inMicroseconds"),
+                DurationUnit::Milliseconds => format!("{key}{null_mark}.inMilliseconds"),
+            },
+            ConcreteType::DateTime => match options.datetime {
+                DateTimeFormat::Iso8601 => format!("{key}{null_mark}.toIso8601String()"),
+                DateTimeFormat::EpochMillis => format!("{key}{null_mark}.millisecondsSinceEpoch"),
+                DateTimeFormat::EpochSeconds if self.nullable => {
+                    format!("{key} == null ? null : {non_null}.millisecondsSinceEpoch ~/ 1000")
+                }
+                DateTimeFormat::EpochSeconds => format!("{key}.millisecondsSinceEpoch ~/ 1000"),
+            },
+            ConcreteType::Custom(_) => {
+                let method = options.encode.as_deref().unwrap_or("toJson");
+                format!("{key}{null_mark}.{method}()")
+            }
+            ConcreteType::TypeParameter(name) if self.nullable => {
+                format!("{key} == null ? null : toJson{name}({non_null})")
+            }
+            ConcreteType::TypeParameter(name) => format!("toJson{name}({key})"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DartType {
+    Concrete(Concrete),
+    GenericList {
+        typ: Box<DartType>,
+        nullable: bool,
+    },
+    GenericSet {
+        typ: Box<DartType>,
+        nullable: bool,
+    },
+    /// Decoded lazily, encoded as a list
+    GenericIterable {
+        typ: Box<DartType>,
+        nullable: bool,
+    },
+    GenericMap {
+        key: Concrete,
+        value: Box<DartType>,
+        nullable: bool,
+    },
+    /// Positional record, as a JSON list
+    Record {
+        fields: Vec<DartType>,
+        nullable: bool,
+    },
+}
+impl DartType {
+    fn from_string_and_options(
+        name: String,
+        options: &FieldOptions,
+        enums: &HashSet<String>,
+        type_parameters: &[String],
+    ) -> Self {
+        let nullable = name.ends_with('?');
+        // kept as is, only usable in fields ignored in JSON
+        if FUNCTION_TYPE_REGEX.is_match(&name) {
+            let typ = name.strip_suffix('?').unwrap_or(&name).to_string();
+            return Self::Concrete(Concrete::new(ConcreteType::Custom(typ), nullable));
+        }
+        // named fields are not supported, e.g. `({double lat, double lng})`
+        if let Some(inner) = name
+            .trim_end_matches('?')
+            .strip_prefix('(')
+            .and_then(|n| n.strip_suffix(')'))
+            && !inner.contains('{')
+        {
+            let fields = split_top_level(inner)
+                .into_iter()
+                .filter(|f| !f.is_empty())
+                // dropping the names of positional fields, as in `(double lat, double lng)`
+                .map(|f| match f.rsplit_once(' ') {
+                    Some((typ, _)) if split_top_level(typ).len() == 1 => typ.to_string(),
+                    _ => f.to_string(),
+                })
+                .map(|f| Self::from_string_and_options(f, options, enums, type_parameters))
+                .collect();
+            return Self::Record { fields, nullable };
+        }
+        if let Some([key, value]) = type_arguments(&name, "Map").as_deref()
+            && !key.is_empty()
+            && key
+                .trim_end_matches('?')
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_')
+        {
+            let mut key = Concrete::from_string(key);
+            // an enum key, declared in the file or marked with the `enum` option
+            if let ConcreteType::Custom(name) = &key.typ
+                && (options.is_enum || enums.contains(name.as_str()))
+            {
+                key.typ = ConcreteType::Enum(name.clone());
+            }
+            return Self::GenericMap {
+                key,
+                value: Box::new(Self::from_string_and_options(
+                    value.to_string(),
+                    options,
+                    enums,
+                    type_parameters,
+                )),
+                nullable,
+            };
+        }
+        if let Some([typ]) = type_arguments(&name, "Iterable").as_deref() {
+            return Self::GenericIterable {
+                typ: Box::new(Self::from_string_and_options(
+                    typ.to_string(),
+                    options,
+                    enums,
+                    type_parameters,
+                )),
+                nullable,
+            };
+        }
+        if let Some([typ]) = type_arguments(&name, "Set").as_deref() {
+            return Self::GenericSet {
+                typ: Box::new(Self::from_string_and_options(
+                    typ.to_string(),
+                    options,
+                    enums,
+                    type_parameters,
+                )),
+                nullable,
+            };
+        }
+        match type_arguments(&name, "List").as_deref() {
+            // the element can itself be a collection, e.g. List<List<double>>
+            Some([typ]) => Self::GenericList {
+                typ: Box::new(Self::from_string_and_options(
+                    typ.to_string(),
+                    options,
+                    enums,
+                    type_parameters,
+                )),
+                nullable,
+            },
+            _ if type_parameters
+                .iter()
+                .any(|p| p == name.trim_end_matches('?')) =>
+            {
+                Self::Concrete(Concrete::new(
+                    ConcreteType::TypeParameter(name.replace("?", "")),
+                    nullable,
+                ))
+            }
+            // enums declared in the file don't need the `enum` option
+            _ if options.is_enum || enums.contains(name.trim_end_matches('?')) => Self::Concrete(
+                Concrete::new(ConcreteType::Enum(name.replace("?", "")), nullable),
+            ),
+            _ => Self::Concrete(Concrete::from_string(&name)),
+        }
+    }
+
+    fn type_string(&self) -> String {
+        let null_mark = if self.is_nullable() { "?" } else { "" };
+        match self {
+            DartType::Concrete(concrete) => concrete.type_string(),
+            _ => self.non_null_type_string() + null_mark,
+        }
+    }
+
+    fn non_null_type_string(&self) -> String {
+        match self {
+            DartType::Concrete(concrete) => concrete.non_null_type_string(),
+            DartType::GenericList { typ, .. } => format!("List<{}>", typ.type_string()),
+            DartType::GenericSet { typ, .. } => format!("Set<{}>", typ.type_string()),
+            DartType::GenericIterable { typ, .. } => format!("Iterable<{}>", typ.type_string()),
+            DartType::GenericMap { key, value, .. } => {
+                format!("Map<{}, {}>", key.type_string(), value.type_string())
+            }
+            DartType::Record { fields, .. } => {
+                let types: Vec<String> = fields.iter().map(|f| f.type_string()).collect();
+                // a record with a single positional field needs a trailing comma
+                let comma = if fields.len() == 1 { "," } else { "" };
+                format!("({}{comma})", types.join(", "))
+            }
+        }
+    }
+
+    fn is_nullable(&self) -> bool {
+        match self {
+            DartType::Concrete(concrete) => concrete.nullable,
+            DartType::GenericList { nullable, .. }
+            | DartType::GenericSet { nullable, .. }
+            | DartType::GenericIterable { nullable, .. }
+            | DartType::GenericMap { nullable, .. }
+            | DartType::Record { nullable, .. } => *nullable,
+        }
+    }
+
+    /// Empty const value of a collection
+    fn empty_literal(&self) -> Option<String> {
+        match self {
+            DartType::GenericList { .. } | DartType::GenericIterable { .. } => {
+                Some("const []".to_string())
+            }
+            // `{}` alone is a map
+            DartType::GenericSet { typ, .. } => Some(format!("const <{}>{{}}", typ.type_string())),
+            DartType::GenericMap { .. } => Some("const {}".to_string()),
+            _ => None,
+        }
+    }
+
+    fn is_dynamic(&self) -> bool {
+        matches!(
+            self,
+            DartType::Concrete(Concrete {
+                typ: ConcreteType::Dynamic,
+                ..
+            })
+        )
+    }
+
+    /// Key type of a map, at any depth, that can't be decoded from a JSON object key
+    fn unsupported_map_key(&self) -> Option<String> {
+        match self {
+            DartType::Concrete(_) => None,
+            DartType::GenericMap { key, value, .. } => match key.from_json_key("k") {
+                None => Some(key.type_string()),
+                Some(_) => value.unsupported_map_key(),
+            },
+            DartType::GenericList { typ, .. }
+            | DartType::GenericSet { typ, .. }
+            | DartType::GenericIterable { typ, .. } => typ.unsupported_map_key(),
+            DartType::Record { fields, .. } => fields.iter().find_map(|f| f.unsupported_map_key()),
+        }
+    }
+
+    fn is_collection(&self) -> bool {
+        matches!(
+            self,
+            DartType::GenericList { .. }
+                | DartType::GenericSet { .. }
+                | DartType::GenericIterable { .. }
+                | DartType::GenericMap { .. }
+        )
+    }
+
+    /// Whether the value must be converted before it can be put in a JSON map
+    fn needs_json_conversion(&self) -> bool {
+        match self {
+            DartType::Concrete(concrete) => concrete.needs_json_conversion(),
+            DartType::GenericList { typ, .. } => typ.needs_json_conversion(),
+            // JSON has no set, so it is always converted to a list
+            DartType::GenericSet { .. } | DartType::GenericIterable { .. } => true,
+            DartType::GenericMap { key, value, .. } => {
+                !key.is_string_key() || value.needs_json_conversion()
+            }
+            // JSON has no record, so it is always converted to a list
+            DartType::Record { .. } => true,
+        }
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_json_value(&self, key: String, options: &FieldOptions) -> String {
+        let null_mark = if self.is_nullable() { "?" } else { "" };
+        match self {
+            DartType::Concrete(concrete) => concrete.from_json_value(key, options),
+            // decoded JSON values are already dynamic
+            DartType::GenericList { typ, .. } if typ.is_dynamic() => {
+                format!("{key} as List<dynamic>{null_mark}")
+            }
+            DartType::GenericSet { typ, .. } if typ.is_dynamic() => {
+                format!("({key} as List{null_mark}){null_mark}.toSet()")
+            }
+            DartType::GenericMap {
+                key: map_key,
+                value,
+                ..
+            } if map_key.is_string_key() && value.is_dynamic() => {
+                format!("{key} as Map<String, dynamic>{null_mark}")
+            }
+            DartType::GenericList { typ, .. } => {
+                let mapper = format!("(e) => {}", typ.from_json_value("e".to_string(), options));
+                format!("({key} as List{null_mark}){null_mark}.map({mapper}).toList()")
+            }
+            DartType::GenericSet { typ, .. } => {
+                let mapper = format!("(e) => {}", typ.from_json_value("e".to_string(), options));
+                format!("({key} as List{null_mark}){null_mark}.map({mapper}).toSet()")
+            }
+            DartType::GenericIterable { typ, .. } => {
+                let mapper = format!("(e) => {}", typ.from_json_value("e".to_string(), options));
+                format!("({key} as List{null_mark}){null_mark}.map({mapper})")
+            }
+            DartType::GenericMap {
+                key: map_key,
+                value,
+                ..
+            } => {
+                // unsupported key types are rejected by the parser
+                let mapper = format!(
+                    "(k, e) => MapEntry({}, {})",
+                    map_key.from_json_key("k").unwrap_or("k".to_string()),
+                    value.from_json_value("e".to_string(), options)
+                );
+                format!("({key} as Map<String, dynamic>{null_mark}){null_mark}.map({mapper})")
+            }
+            DartType::Record { fields, nullable } => {
+                let values: Vec<String> = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| f.from_json_value(format!("({key} as List)[{i}]"), options))
+                    .collect();
+                let comma = if fields.len() == 1 { "," } else { "" };
+                let null_guard = if *nullable {
+                    format!("{key} == null ? null : ")
+                } else {
+                    "".to_string()
+                };
+                format!("{null_guard}({}{comma})", values.join(", "))
+            }
+        }
+    }
+
+    /// Encodes the value of `key`, `promoted` as for `Concrete::to_json_value`
+    fn to_json_value(&self, key: String, options: &FieldOptions, promoted: bool) -> String {
+        let null_mark = if self.is_nullable() { "?" } else { "" };
+        match self {
+            DartType::Concrete(concrete) => concrete.to_json_value(key, options, promoted),
+            _ if !self.needs_json_conversion() => key,
+            DartType::GenericList { typ, .. } => {
+                let mapper = format!(
+                    "(e) => {}",
+                    typ.to_json_value("e".to_string(), options, true)
+                );
+                format!("{key}{null_mark}.map({mapper}).toList()")
+            }
+            DartType::GenericSet { typ, .. } | DartType::GenericIterable { typ, .. } => {
+                if typ.needs_json_conversion() {
+                    let mapper = format!(
+                        "(e) => {}",
+                        typ.to_json_value("e".to_string(), options, true)
+                    );
+                    format!("{key}{null_mark}.map({mapper}).toList()")
+                } else {
+                    format!("{key}{null_mark}.toList()")
+                }
+            }
+            DartType::GenericMap {
+                key: map_key,
+                value,
+                ..
+            } => {
+                let mapper = format!(
+                    "(k, e) => MapEntry({}, {})",
+                    map_key.to_json_key("k"),
+                    value.to_json_value("e".to_string(), options, true)
+                );
+                format!("{key}{null_mark}.map({mapper})")
+            }
+            DartType::Record { fields, nullable } => {
+                let (null_guard, access) = if *nullable && promoted {
+                    (format!("{key} == null ? null : "), key)
+                } else if *nullable {
+                    (format!("{key} == null ? null : "), format!("{key}!"))
+                } else {
+                    ("".to_string(), key)
+                };
+                let values: Vec<String> = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| f.to_json_value(format!("{access}.${}", i + 1), options, false))
+                    .collect();
+                format!("{null_guard}[{}]", values.join(", "))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct FieldOptions {
+    key: Option<String>,
+    /// Dotted path of the key in nested maps, set by `path` instead of `key`
+    is_path: bool,
+    /// Naming convention of the key overriding `--key-case`, set by `case`
+    key_case: Option<KeyCase>,
+    is_enum: bool,
+    /// Dart expression used when the JSON value is missing or null
+    default_value: Option<String>,
+    datetime: DateTimeFormat,
+    duration: DurationUnit,
+    /// Enum value used when the JSON value matches none of the values
+    enum_fallback: Option<String>,
+    /// Enum serialized as its index instead of its name
+    is_enum_index: bool,
+    /// Function decoding the JSON value, replacing the type based conversion
+    from_json_fn: Option<String>,
+    /// Function encoding the field value, replacing the type based conversion
+    to_json_fn: Option<String>,
+    /// Named constructor decoding a custom type instead of `fromJson`, set by `factory`
+    factory: Option<String>,
+    /// Method encoding a custom type instead of `toJson`, set by `encode`
+    encode: Option<String>,
+    /// Field left out of JSON, set by `ignore` or `transient`. It is still a
+    /// constructor parameter, in `copyWith`, `==` and `toString`. `fromJson`
+    /// relies on the `default` option or passes null for nullable fields, a
+    /// non-nullable field without a default can't be created by `fromJson`.
+    /// Unlike `computed`, the value is given rather than derived.
+    ignore: bool,
+    /// Field can't be changed through `copyWith`, set by `copyWith=false`
+    skip_copy_with: bool,
+    /// Null value left out of `toJson`, set by `omitIfNull`
+    omit_if_null: bool,
+    /// Nullable collection decoded as empty when missing or null, set by `emptyIfNull`
+    empty_if_null: bool,
+    /// Constraints checked by `validate`, set by `min`, `max`, `minLength`,
+    /// `maxLength` and `notEmpty`
+    min: Option<String>,
+    max: Option<String>,
+    min_length: Option<String>,
+    max_length: Option<String>,
+    not_empty: bool,
+    /// Expression initializing the field in the constructor, set by `computed`.
+    /// The field is not a parameter and is only in `toJson` with `serialize`.
+    computed: Option<String>,
+    serialize: bool,
+    /// Int decoding failing on numbers with a fraction instead of truncating them,
+    /// set by `strictInt`
+    strict_int: bool,
+    /// Numbers also decoded from numeric strings, set by `coerce` or `--coerce-numbers`
+    coerce: bool,
+    /// Custom value kept as is when it is already decoded, set by `lenient`
+    lenient: bool,
+    /// Nullable collection generated as a non-nullable field defaulting to
+    /// an empty collection, set by `emptyDefault`
+    empty_default: bool,
+    /// Field left out of `==`, `hashCode` and props, set by `equalsIgnore`
+    equals_ignore: bool,
+    /// JSON strings of enum values, as `(value, string)` pairs set by
+    /// `values="active:A,inactive:I"`, the other values use their name
+    enum_values: Vec<(String, String)>,
+}
+impl FieldOptions {
+    /// Const map literal from the enum values to their JSON string
+    fn enum_values_map(&self, name: &str) -> Option<String> {
+        if self.enum_values.is_empty() {
+            return None;
+        }
+        let entries: Vec<String> = self
+            .enum_values
+            .iter()
+            .map(|(value, string)| format!("{name}.{value}: '{string}'"))
+            .collect();
+        Some(format!("const {{{}}}", entries.join(", ")))
+    }
+
+    fn has_constraints(&self) -> bool {
+        self.min.is_some()
+            || self.max.is_some()
+            || self.min_length.is_some()
+            || self.max_length.is_some()
+            || self.not_empty
+    }
+
+    fn from_string(value: &str) -> Self {
+        let mut options = FieldOptions::default();
+        for cap in FIELD_OPTIONS_REGEX.captures_iter(value) {
+            if let (Some(k), v) = (cap.name("key"), cap.name("value")) {
+                match v {
+                    Some(v) => {
+                        let mut value = v.as_str();
+                        let quoted = value.starts_with('"') && value.ends_with('"');
+                        if quoted {
+                            value = &value[1..value.len() - 1];
+                        }
+                        match k.as_str() {
+                            "key" => options.key = Some(value.to_string()),
+                            "path" => {
+                                options.key = Some(value.to_string());
+                                options.is_path = true;
+                            }
+                            "fallback" => options.enum_fallback = Some(value.to_string()),
+                            "fromJson" => options.from_json_fn = Some(value.to_string()),
+                            "toJson" => options.to_json_fn = Some(value.to_string()),
+                            "factory" => options.factory = Some(value.to_string()),
+                            "encode" => options.encode = Some(value.to_string()),
+                            "copyWith" => options.skip_copy_with = value == "false",
+                            "case" => options.key_case = KeyCase::from_str(value, false).ok(),
+                            "computed" => options.computed = Some(value.to_string()),
+                            "min" => options.min = Some(value.to_string()),
+                            "max" => options.max = Some(value.to_string()),
+                            "minLength" => options.min_length = Some(value.to_string()),
+                            "maxLength" => options.max_length = Some(value.to_string()),
+                            "values" => {
+                                options.enum_values = value
+                                    .split(',')
+                                    .filter_map(|pair| pair.split_once(':'))
+                                    .map(|(v, s)| (v.trim().to_string(), s.trim().to_string()))
+                                    .collect()
+                            }
+                            "datetime" => match value {
+                                "iso8601" => options.datetime = DateTimeFormat::Iso8601,
+                                "epochMillis" => options.datetime = DateTimeFormat::EpochMillis,
+                                "epochSeconds" => options.datetime = DateTimeFormat::EpochSeconds,
+                                _ => {}
+                            },
+                            "duration" => match value {
+                                "micros" => options.duration = DurationUnit::Microseconds,
+                                "millis" => options.duration = DurationUnit::Milliseconds,
+                                _ => {}
+                            },
+                            "default" => {
+                                // a quoted default is a string literal, otherwise an expression
+                                options.default_value = Some(if quoted {
+                                    format!("'{}'", value.replace('\'', "\\'"))
+                                } else {
+                                    value.to_string()
+                                })
+                            }
+                            _ => {}
+                        }
+                    }
+                    None => match k.as_str() {
+                        "enum" => options.is_enum = true,
+                        "index" => options.is_enum_index = true,
+                        "ignore" | "transient" => options.ignore = true,
+                        "omitIfNull" => options.omit_if_null = true,
+                        "emptyIfNull" => options.empty_if_null = true,
+                        "notEmpty" => options.not_empty = true,
+                        "serialize" => options.serialize = true,
+                        "strictInt" => options.strict_int = true,
+                        "coerce" => options.coerce = true,
+                        "lenient" => options.lenient = true,
+                        "emptyDefault" => options.empty_default = true,
+                        "equalsIgnore" => options.equals_ignore = true,
+                        _ => {}
+                    },
+                }
+            }
+        }
+        options
+    }
+}
+
+/// How `copyWith` parameters of nullable fields can set null
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum CopyWithStyle {
+    /// `T? name` with `??`, null keeps the current value
+    Plain,
+    /// `Object? name = _sentinel`, an explicit null sets null
+    Sentinel,
+    /// `ValueGetter<T?>? name` from package:flutter, `() => null` sets null
+    #[value(name = "valuegetter")]
+    ValueGetter,
+}
+
+/// Trailing comma after the last element of multi-line lists
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum TrailingComma {
+    /// As recommended by the Dart style, also keeping the lists wrapped
+    Always,
+    Never,
+}
+
+/// JSON representation of a `DateTime` field
+#[derive(Debug, Default, Clone, Copy)]
+pub enum DateTimeFormat {
+    /// ISO-8601 string
+    #[default]
+    Iso8601,
+    /// Integer milliseconds since epoch
+    EpochMillis,
+    /// Integer seconds since epoch
+    EpochSeconds,
+}
+
+/// Absolute path with `.` and `..` resolved, without touching the file system
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let path = env::current_dir().unwrap_or_default().join(path);
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            c => normalized.push(c),
+        }
+    }
+    normalized
+}
+
+/// Path relative to the current directory, or without its root when outside of it
+fn relative_to_current_dir(path: &Path) -> PathBuf {
+    let path = normalize_path(path);
+    let current_dir = normalize_path(Path::new("."));
+    match path.strip_prefix(&current_dir) {
+        Ok(relative) => relative.to_path_buf(),
+        Err(_) => path
+            .components()
+            .filter(|c| matches!(c, Component::Normal(_)))
+            .collect(),
+    }
+}
+
+/// Relative path from a directory to a file, `/` separated as in Dart URIs
+pub fn relative_path(from_dir: &Path, to: &Path) -> String {
+    let from: Vec<Component> = from_dir.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+    parts.extend(
+        to[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().to_string()),
+    );
+    parts.join("/")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    /// Only reported in verbose mode
+    Info,
+    Hint,
+    Warning,
+    Error,
+}
+
+/// A problem found while parsing a file
+#[derive(Debug)]
+pub struct Diagnostic {
+    /// 0 based index of the line
+    line: usize,
+    level: Level,
+    message: String,
+}
+impl Diagnostic {
+    fn info(line: usize, message: String) -> Self {
+        Self {
+            line,
+            level: Level::Info,
+            message,
+        }
+    }
+
+    fn hint(line: usize, message: String) -> Self {
+        Self {
+            line,
+            level: Level::Hint,
+            message,
+        }
+    }
+
+    fn warning(line: usize, message: String) -> Self {
+        Self {
+            line,
+            level: Level::Warning,
+            message,
+        }
+    }
+
+    pub fn error(line: usize, message: String) -> Self {
+        Self {
+            line,
+            level: Level::Error,
+            message,
+        }
+    }
+}
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.level {
+            Level::Info => "info",
+            Level::Hint => "hint",
+            Level::Warning => "warning",
+            Level::Error => "error",
+        };
+        write!(f, "{}: {level}: {}", self.line + 1, self.message)
+    }
+}
+
+/// JSON representation of a `Duration` field, as an integer
+#[derive(Debug, Default, Clone, Copy)]
+pub enum DurationUnit {
+    #[default]
+    Microseconds,
+    Milliseconds,
+}
@@ -1,626 +1,554 @@
-use clap::Parser;
-use glob::glob;
-use regex::Regex;
-use std::{fs, path::Path, thread};
-
-const FLU_ANNOTATION: &str = "// @flu";
-const CLASS_REGEX: &str = r"^abstract class _(\w+) \{";
-const FIELD_REGEX: &str = r"^\s\s([A-Za-z_].*) get (\w+);$";
-const FIELD_ANNOTATION_REGEX: &str = r#"^  // @flu (.*)$"#;
-const FIELD_OPTIONS_REGEX: &str = r#"(?P<key>\w+)(?:=(?P<value>"[^"]+"|\S+))?"#;
-const GENERIC_LIST_REGEX: &str = r"^List<([A-Za-z_].*)>";
-
-// TODO: deep collection
-
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-struct Args {
-    /// Path to dart files
-    #[arg(short, long, default_value = "lib/**/*.dart")]
-    path: String,
-}
+use clap::{
+    Arg, ArgAction, ArgMatches, Command as ArgsCommand, CommandFactory, FromArgMatches,
+    error::ErrorKind, parser::ValueSource,
+};
+use glob::{Pattern, glob};
+use rust_dart_gen::{
+    Args, Config, DartFile, Diagnostic, IGNORE_FILE, normalize_path, relative_path,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    ffi::OsString,
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    thread::available_parallelism,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Config file with default values of the command line options, see `config_file_args`
+const CONFIG_FILE: &str = "flu.conf";
+/// How often watch mode checks files for changes
+const WATCH_INTERVAL: Duration = Duration::from_millis(200);
+/// How long a file must stay unchanged before it is regenerated in watch mode
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
 
 fn main() {
-    let args = Args::parse();
-
-    let mut dart_paths: Vec<String> = vec![];
-    for entry in glob(&args.path).expect("Failed to read glob pattern") {
-        match entry {
-            Err(_) => (),
-            Ok(path) => {
-                let p = path.to_string_lossy().to_string();
-                if !p.ends_with(".flu.dart")
-                    && !p.ends_with(".g.dart")
-                    && !p.ends_with(".freezed.dart")
-                {
-                    dart_paths.push(p);
-                }
-            }
+    let cli_args: Vec<OsString> = env::args_os().collect();
+    let command = command();
+    let cli_matches = command.clone().get_matches_from(&cli_args);
+    let file_args = match config_file_args(Path::new(CONFIG_FILE), &command, &cli_matches) {
+        Ok(file_args) => file_args,
+        Err(e) => {
+            eprintln!("{CONFIG_FILE}:{e}");
+            process::exit(1);
         }
+    };
+    // the options of the file first, as if given before the command line ones
+    let matches = command
+        .clone()
+        .get_matches_from(cli_args[..1].iter().chain(&file_args).chain(&cli_args[1..]));
+    let args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let config = args
+        .config()
+        .unwrap_or_else(|e| command.clone().error(ErrorKind::ArgumentConflict, e).exit());
+
+    if args.stdin {
+        let name = args.stdin_name.as_deref().unwrap_or("<stdin>");
+        if process_stdin(name, &config) {
+            process::exit(1);
+        }
+        return;
     }
 
-    // true == enable multi-threading
-    if true {
-        // TODO: thread count?
-        // let x = available_parallelism().unwrap().get();
-        // println!("Available parallelism: {x}");
-        let parts: Vec<Vec<String>> = dart_paths
-            // .chunks(dart_paths.len() / x) // based on cpu
-            .chunks(1) // max threads
-            .map(|e| e.to_vec())
-            .collect();
+    let start = Instant::now();
+    let dart_paths = dart_paths(&args.path, &config, true);
+
+    let mut processed: Vec<(String, Processed)> = vec![];
+    // set on the first error in fail fast mode, telling every thread to stop
+    let stop = Arc::new(AtomicBool::new(false));
+    let threads = args
+        .threads
+        .unwrap_or_else(|| available_parallelism().map_or(1, |n| n.get()))
+        .max(1);
+    if threads > 1 {
+        // splitting files into roughly equal parts, one per thread
+        let chunk_size = dart_paths.len().div_ceil(threads).max(1);
+        let parts: Vec<Vec<String>> = dart_paths.chunks(chunk_size).map(|e| e.to_vec()).collect();
         let mut handle = vec![];
         for part in parts {
+            let config = config.clone();
+            let stop = Arc::clone(&stop);
+            let fail_fast = args.fail_fast;
             handle.push(thread::spawn(move || {
+                let mut results = vec![];
                 for path in part {
-                    if let Ok(file) = DartFile::from_file(&path) {
-                        file.generate_file();
+                    if stop.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    let result = process_file(&path, &config);
+                    if fail_fast && result.has_error {
+                        stop.store(true, Ordering::Relaxed);
                     }
+                    results.push((path, result));
                 }
+                results
             }));
         }
-        handle.into_iter().for_each(|h| h.join().unwrap());
+        // the parts are in the order of the files
+        for h in handle {
+            let part = h.join().unwrap();
+            part.iter().for_each(|(_, p)| p.flush());
+            processed.extend(part);
+        }
     } else {
         for path in &dart_paths {
-            if let Ok(file) = DartFile::from_file(&path) {
-                file.generate_file();
+            let result = process_file(path, &config);
+            result.flush();
+            let has_error = result.has_error;
+            processed.push((path.clone(), result));
+            if args.fail_fast && has_error {
+                stop.store(true, Ordering::Relaxed);
+                break;
             }
         }
     }
-}
-
-#[derive(Debug)]
-struct DartFile {
-    path: String,
-    classes: Vec<DartClass>,
-}
-impl DartFile {
-    fn new(path: String, classes: Vec<DartClass>) -> Self {
-        Self { path, classes }
-    }
-
-    fn from_file(path: &str) -> Result<Self, std::io::Error> {
-        let content = fs::read_to_string(path)?;
-        return Ok(DartFile::from_string(&content, path));
+    // warnings fail the run too, so that a pipeline notices skipped fields
+    let mut has_error = processed.iter().any(|(_, p)| p.has_error || p.has_warning);
+    // on stderr, as generated files are printed to stdout in stdout mode
+    eprintln!(
+        "Scanned {} files, generated {} classes, wrote {} files in {:.2?}",
+        processed.len(),
+        processed.iter().map(|(_, p)| p.classes).sum::<usize>(),
+        processed.iter().filter(|(_, p)| p.written).count(),
+        start.elapsed()
+    );
+    if stop.load(Ordering::Relaxed) {
+        let skipped = dart_paths.len() - processed.len();
+        if skipped > 0 {
+            eprintln!("Stopped at the first error, skipped {skipped} files");
+        }
+        process::exit(1);
     }
 
-    fn from_string(content: &str, path: &str) -> Self {
-        let class_regex = Regex::new(CLASS_REGEX).unwrap();
-        let field_regex = Regex::new(FIELD_REGEX).unwrap();
-        let field_comment_regex = Regex::new(FIELD_ANNOTATION_REGEX).unwrap();
-
-        let lines: Vec<String> = content.lines().map(String::from).collect();
-        let mut classes: Vec<DartClass> = vec![];
-
-        let mut annotation_start = false;
-        let mut class_start = false;
-        let mut depth = 0; // scopes { }
-
-        // parsing all classes and their fields in a single loop
-        for (i, line) in lines.iter().enumerate() {
-            if !annotation_start {
-                annotation_start = line == FLU_ANNOTATION;
-                continue;
-            }
-
-            // removing comment from line
-            let line = if !line.trim_start().starts_with("// @flu: ") {
-                line.split("//").next().unwrap().trim_end()
-            } else {
-                continue;
-            };
-            if line.trim_start().is_empty() {
-                continue;
-            }
-
-            // checking for a class declaration
-            if !class_start {
-                if let Some(cap) = class_regex.captures(line) {
-                    // start of a @flu class
-                    classes.push(DartClass::new(cap[1].to_string(), false, vec![]));
-                    if !line.ends_with("}") {
-                        class_start = true;
-                        depth = 1;
-                    }
-                }
-                continue;
-            }
-
-            // checking for const constructor
-            if depth == 1
-                && line == format!("  const _{}();", classes.last().unwrap().name.to_string())
-            {
-                classes.last_mut().unwrap().has_const_constructor = true;
-                continue;
-            }
-
-            // checking fields inside class
-            if depth == 1
-                && let Some(cap) = field_regex.captures(line)
-            {
-                // checking for field options above the field
-                let options = match field_comment_regex.captures(&lines[i - 1]) {
-                    Some(cap) => Some(FieldOptions::from_string(&cap[1])),
-                    None => None,
-                };
-                classes.last_mut().unwrap().fields.push(DartField::new(
-                    cap[2].to_string(),
-                    DartType::from_string_and_options(cap[1].to_string(), &options),
-                    options,
-                ));
-            } else {
-                // for skipping method declarations
-                if line.contains("{") {
-                    depth += 1;
-                }
-                if line.contains("}") {
-                    depth -= 1
-                };
-                if depth == 0 && class_start {
-                    // end of a @flu class
-                    class_start = false;
-                    annotation_start = false;
-                }
-            }
+    if let Some(index) = &args.index
+        && !args.check
+    {
+        let sources: Vec<&str> = processed
+            .iter()
+            .filter(|(_, p)| p.classes > 0)
+            .map(|(path, _)| path.as_str())
+            .collect();
+        if let Err(e) = write_index(index, &sources) {
+            eprintln!("{}: error: {e}", index.display());
+            has_error = true;
         }
-        DartFile::new(path.to_string(), classes)
     }
 
-    fn generated_path(&self) -> String {
-        self.path.replace(".dart", ".flu.dart")
+    if args.watch {
+        watch(&args.path, &config);
+    }
+    if has_error {
+        process::exit(1);
     }
+}
 
-    fn file_name(&self) -> String {
-        Path::new(&self.path)
-            .file_name()
-            .unwrap()
-            .to_str()
-            .unwrap()
-            .to_string()
+/// Command line options, with a hidden `--no-<flag>` negation of each
+/// boolean flag for turning off a flag set in the config file
+fn command() -> ArgsCommand {
+    let mut command = Args::command().after_help(format!(
+        "Defaults of the options can be set in a {CONFIG_FILE} file, boolean options set \
+         there are turned off with `--no-<option>`"
+    ));
+    let flags: Vec<(String, String)> = command
+        .get_arguments()
+        .filter(|a| matches!(a.get_action(), ArgAction::SetTrue))
+        .filter_map(|a| Some((a.get_id().to_string(), a.get_long()?.to_string())))
+        .collect();
+    for (id, long) in flags {
+        let negation = format!("no-{long}");
+        command = command
+            .mut_arg(&id, |a| a.overrides_with(negation.clone()))
+            .arg(
+                Arg::new(negation.clone())
+                    .long(negation)
+                    .action(ArgAction::SetTrue)
+                    .overrides_with(id)
+                    .hide(true),
+            );
     }
+    command
+}
 
-    fn generate_file(&self) {
-        if self.classes.is_empty() {
-            return;
+/// Reads the config file as command line arguments. A missing file is empty.
+///
+/// Each line is a `key: value` option, the keys being the long option names
+/// with `-` or `_`, as in `key_case: snake`. Values can be quoted with `'` or
+/// `"`, the latter escaping with `\`. Options taking several values take comma
+/// separated values, as in `path: lib, test`, and boolean options take `true`
+/// or `false`. A `#` at the start of a line or after a space starts a comment.
+/// This is not YAML, lists and nested values are reported as errors.
+///
+/// Options given on the command line override the ones in the file.
+fn config_file_args(
+    path: &Path,
+    command: &ArgsCommand,
+    cli_matches: &ArgMatches,
+) -> Result<Vec<OsString>, Diagnostic> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+    let mut args = vec![];
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim_end();
+        if line.trim_start().is_empty() || line.trim_start().starts_with('#') {
+            continue;
         }
-        let ignores = "// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars";
-        let mut lines = vec![
-            "// dart format off\n".to_string(),
-            ignores.to_string(),
-            format!("\npart of '{}';", self.file_name()),
-        ];
-        for class in &self.classes {
-            // class definition start
-            lines.push(format!("\nclass {} extends _{} {{", class.name, class.name));
-
-            Self::add_constructor(class, &mut lines);
-
-            Self::add_from_json(class, &mut lines);
-
-            Self::add_fields(class, &mut lines);
-
-            Self::add_to_json(class, &mut lines);
-
-            Self::add_copy_with(class, &mut lines);
-
-            Self::add_to_string(class, &mut lines);
-
-            Self::add_equal_operator(class, &mut lines);
-
-            Self::add_hash_code(class, &mut lines);
-
-            // class definition end
-            lines.push("}\n".to_string());
+        if line.starts_with(char::is_whitespace) {
+            return Err(Diagnostic::error(
+                i,
+                "nested values are not supported, expected `key: value`".to_string(),
+            ));
         }
-
-        let _ = fs::write(self.generated_path(), lines.join("\n"));
-    }
-
-    fn add_constructor(class: &DartClass, lines: &mut Vec<String>) {
-        let const_key = if class.has_const_constructor {
-            "const "
-        } else {
-            ""
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(Diagnostic::error(i, "expected `key: value`".to_string()));
         };
-        if class.fields.is_empty() {
-            lines.push(format!("  {const_key}{}();", class.name));
-        } else {
-            lines.push(format!("  {const_key}{}({{", class.name));
-            for field in &class.fields {
-                lines.push(format!("    required this.{},", field.name));
-            }
-            lines.push("  });".to_string());
+        let key = key.trim();
+        let values =
+            config_values(value).map_err(|e| Diagnostic::error(i, format!("`{key}`: {e}")))?;
+        let flag = key.replace('_', "-");
+        let Some(arg) = command
+            .get_arguments()
+            .find(|a| a.get_long() == Some(&flag))
+        else {
+            return Err(Diagnostic::error(i, format!("unknown option `{key}`")));
+        };
+        let is_flag = matches!(arg.get_action(), ArgAction::SetTrue);
+        let given = |id: &str| cli_matches.value_source(id) == Some(ValueSource::CommandLine);
+        if given(arg.get_id().as_str()) || is_flag && given(&format!("no-{flag}")) {
+            continue;
         }
-    }
-
-    fn add_from_json(class: &DartClass, lines: &mut Vec<String>) {
-        lines.push(format!(
-            "\n  factory {}.fromJson(Map<String, dynamic> json) {{",
-            class.name
-        ));
-        lines.push(format!("    return {}(", class.name));
-        for field in &class.fields {
-            let DartField { name, typ, .. } = field;
-            let key = field.json_key();
-            let value = match typ {
-                DartType::Concrete(concrete) => concrete.from_json_value(format!("json['{key}']")),
-                DartType::GenericList { typ, nullable } => {
-                    let mapper = format!("(e) => {}", typ.from_json_value("e".to_string()));
-                    let null_mark = if *nullable { "?" } else { "" };
-                    format!(
-                        "(json['{key}'] as List{}){}.map({mapper}).toList()",
-                        null_mark, null_mark
-                    )
+        if is_flag {
+            match values.as_slice() {
+                [value] if value == "true" => args.push(format!("--{flag}").into()),
+                [value] if value == "false" => {}
+                _ => {
+                    return Err(Diagnostic::error(
+                        i,
+                        format!("`{key}` must be `true` or `false`"),
+                    ));
                 }
-            };
-            lines.push(format!("      {name}: {value},"));
-        }
-        lines.push("    );".to_string());
-        lines.push("  }".to_string());
-    }
-
-    fn add_fields(class: &DartClass, lines: &mut Vec<String>) {
-        for field in &class.fields {
-            lines.push(format!(
-                "\n  @override\n  final {} {};",
-                field.typ.type_string(),
-                field.name
+            }
+        } else if values.len() > 1 && !matches!(arg.get_action(), ArgAction::Append) {
+            return Err(Diagnostic::error(
+                i,
+                format!("`{key}` takes a single value"),
             ));
+        } else {
+            args.extend(
+                values
+                    .iter()
+                    .map(|value| format!("--{flag}={value}").into()),
+            );
         }
     }
+    Ok(args)
+}
 
-    fn add_to_json(class: &DartClass, lines: &mut Vec<String>) {
-        lines.push("\n  Map<String, dynamic> toJson() => {".to_string());
-        for field in &class.fields {
-            let DartField { name, typ, .. } = field;
-            let key = field.json_key();
-            let value = match typ {
-                DartType::Concrete(concrete) => concrete.to_json_value(name.to_string()),
-                DartType::GenericList { typ, nullable } => {
-                    if typ.is_custom()
-                        || matches!(typ.typ, ConcreteType::DateTime | ConcreteType::Enum(_))
-                    {
-                        let mapper = format!("(e) => {}", typ.to_json_value("e".to_string()));
-                        let null_mark = if *nullable { "?" } else { "" };
-                        format!("{name}{null_mark}.map({mapper}).toList()")
-                    } else {
-                        name.to_string()
+/// Comma separated values of a config file option, plain or quoted
+fn config_values(text: &str) -> Result<Vec<String>, String> {
+    let mut values = vec![];
+    let mut chars = text.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let value = match chars.peek().copied() {
+            Some(quote @ ('"' | '\'')) => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some(c) if c == quote => break,
+                        Some('\\') if quote == '"' => value.extend(chars.next()),
+                        Some(c) => value.push(c),
+                        None => return Err(format!("unterminated {quote} quote")),
                     }
                 }
-            };
-            lines.push(format!("    '{key}': {value},"));
-        }
-        lines.push("  };".to_string());
-    }
-
-    fn add_copy_with(class: &DartClass, lines: &mut Vec<String>) {
-        if class.fields.is_empty() {
-            lines.push(format!(
-                "\n  {} copyWith() => {}();",
-                class.name, class.name
-            ));
-        } else {
-            lines.push(format!("\n  {} copyWith({{", class.name));
-            for DartField { name, typ, .. } in &class.fields {
-                // no ? for dynamic
-                let null_mark = if matches!(
-                    typ,
-                    DartType::Concrete(Concrete {
-                        typ: ConcreteType::Dynamic,
-                        ..
-                    })
-                ) {
-                    ""
-                } else {
-                    "?"
-                };
-                lines.push(format!(
-                    "    {}{null_mark} {name},",
-                    typ.non_null_type_string()
-                ));
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                value
             }
-            lines.push(format!("  }}) => {}(", class.name));
-            for DartField { name, .. } in &class.fields {
-                lines.push(format!("    {name}: {name} ?? this.{name},"));
+            Some('[' | '{') => {
+                return Err(
+                    "lists and maps are not supported, separate values with commas".to_string(),
+                );
             }
-            lines.push("  );".to_string());
-        }
-    }
-
-    fn add_to_string(class: &DartClass, lines: &mut Vec<String>) {
-        lines.push(format!(
-            "\n  @override\n  String toString() => '{}('",
-            class.name
-        ));
-        for DartField { name, .. } in &class.fields {
-            lines.push(format!("    '{name}: ${name} '",));
-        }
-        lines.push("    ')';".to_string());
-    }
-
-    fn add_equal_operator(class: &DartClass, lines: &mut Vec<String>) {
-        lines.push("\n  @override\n  bool operator ==(Object other) {".to_string());
-        lines.push("    if (identical(this, other)) return true;".to_string());
-        if class.fields.is_empty() {
-            lines.push(format!(
-                "    return other is {} && other == this;",
-                class.name
-            ));
-        } else {
-            lines.push(format!("    return other is {}", class.name));
-            let mut equals = vec![];
-            for DartField { name, .. } in &class.fields {
-                equals.push(format!("      && other.{name} == {name}"));
-            }
-            lines.push(equals.join("\n") + ";");
-        }
-        lines.push("  }".to_string());
-    }
-
-    fn add_hash_code(class: &DartClass, lines: &mut Vec<String>) {
-        lines.push("\n  @override".to_string());
-        if class.fields.is_empty() {
-            lines.push("  int get hashCode => super.hashCode;".to_string());
-        } else {
-            lines.push("  int get hashCode => Object.hashAll([".to_string());
-            for DartField { name, .. } in &class.fields {
-                lines.push(format!("    {}.hashCode,", name));
+            _ => {
+                let mut value = String::new();
+                while let Some(c) = chars.next_if(|&c| {
+                    c != ',' && !(c == '#' && (value.is_empty() || value.ends_with(' ')))
+                }) {
+                    value.push(c);
+                }
+                if value.trim().is_empty() {
+                    return Err("missing value".to_string());
+                }
+                value.trim_end().to_string()
             }
-            lines.push("  ]);".to_string());
+        };
+        values.push(value);
+        match chars.next() {
+            Some(',') => {}
+            Some('#') | None => return Ok(values),
+            Some(c) => return Err(format!("unexpected `{c}` after a quoted value")),
         }
     }
 }
 
-#[derive(Debug)]
-struct DartClass {
-    name: String,
-    has_const_constructor: bool,
-    fields: Vec<DartField>,
-}
-impl DartClass {
-    fn new(name: String, has_const_constructor: bool, fields: Vec<DartField>) -> Self {
-        Self {
-            name,
-            has_const_constructor,
-            fields,
-        }
+/// Prints the file generated from stdin. Returns whether an error or a warning occurred.
+fn process_stdin(name: &str, config: &Config) -> bool {
+    let mut content = String::new();
+    if let Err(e) = io::stdin().read_to_string(&mut content) {
+        eprintln!("{name}: error: {e}");
+        return true;
+    }
+    let file = DartFile::from_string(&content, name, config);
+    let mut log = String::new();
+    let has_error = file.report(&mut log);
+    let has_warning = file.has_warnings();
+    let content = generated_content(&file, config, &mut log);
+    eprint!("{log}");
+    if let Some(content) = content {
+        print!("{content}");
     }
+    has_error || has_warning
 }
 
-#[derive(Debug)]
-struct DartField {
-    name: String,
-    typ: DartType,
-    options: Option<FieldOptions>,
-}
-impl DartField {
-    fn new(name: String, typ: DartType, options: Option<FieldOptions>) -> Self {
-        Self { name, typ, options }
+/// Generated code of a file, formatted with `--format-on`. Failing to format
+/// is only logged, unlike the warnings of the sources it doesn't fail the run.
+fn generated_content(file: &DartFile, config: &Config, log: &mut String) -> Option<String> {
+    let content = file.generate_string(config)?;
+    if !config.format_on {
+        return Some(content);
     }
-
-    fn json_key(&self) -> String {
-        match &self.options {
-            Some(o) => match &o.key {
-                Some(k) => k.clone(),
-                None => self.name.clone(),
-            },
-            None => self.name.clone(),
+    let generated_path = file.generated_path(config);
+    match dart_format(&content, &generated_path) {
+        Ok(formatted) => Some(formatted),
+        Err(e) => {
+            *log += &format!("{generated_path}: warning: not formatted, {e}\n");
+            Some(content)
         }
     }
 }
 
-#[derive(Debug)]
-enum ConcreteType {
-    Int,
-    Double,
-    Bool,
-    String,
-    Dynamic,
-    Enum(String),
-    DateTime,
-    Custom(String),
+/// Formats Dart code with `dart format`, reading it from stdin
+fn dart_format(content: &str, path: &str) -> Result<String, String> {
+    let mut child = Command::new("dart")
+        .args(["format", "--stdin-name", path])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("can't run `dart format`: {e}"))?;
+    // written from another thread, as a large input could fill the output pipe first
+    let mut stdin = child.stdin.take().unwrap();
+    let input = content.to_string();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+    writer.join().unwrap().map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    String::from_utf8(output.stdout).map_err(|e| e.to_string())
 }
 
+/// Result of processing a source file
 #[derive(Debug)]
-struct Concrete {
-    typ: ConcreteType,
-    nullable: bool,
+struct Processed {
+    has_error: bool,
+    /// Whether a warning was reported, failing the run without stopping it
+    has_warning: bool,
+    /// Number of classes to generate
+    classes: usize,
+    /// Whether the generated file was written
+    written: bool,
+    /// Generated code printed in stdout mode
+    output: String,
+    /// Diagnostics and logs printed to stderr
+    log: String,
 }
-impl Concrete {
-    fn new(typ: ConcreteType, nullable: bool) -> Self {
-        Self { typ, nullable }
+impl Processed {
+    /// Prints the buffered output, so the output of files processed by other
+    /// threads doesn't interleave and follows the order of the files
+    fn flush(&self) {
+        eprint!("{}", self.log);
+        print!("{}", self.output);
     }
+}
 
-    fn from_string(name: &str) -> Self {
-        let nullable = name.ends_with('?');
-        match name.replace("?", "").as_str() {
-            "int" => Self::new(ConcreteType::Int, nullable),
-            "double" => Self::new(ConcreteType::Double, nullable),
-            "bool" => Self::new(ConcreteType::Bool, nullable),
-            "dynamic" => Self::new(ConcreteType::Dynamic, false),
-            "String" => Self::new(ConcreteType::String, nullable),
-            "DateTime" => Self::new(ConcreteType::DateTime, nullable),
-            custom => Self::new(ConcreteType::Custom(custom.to_string()), nullable),
+/// Generates the file for a source file, or prints it in stdout mode
+fn process_file(path: &str, config: &Config) -> Processed {
+    let file = match DartFile::from_file(path, config) {
+        Ok(file) => file,
+        Err(e) => {
+            return Processed {
+                has_error: true,
+                has_warning: false,
+                classes: 0,
+                written: false,
+                output: String::new(),
+                log: format!("{path}: error: {e}\n"),
+            };
         }
+    };
+    let mut log = String::new();
+    let mut output = String::new();
+    let mut has_error = file.report(&mut log);
+    let has_warning = file.has_warnings();
+    if config.verbose {
+        file.log_classes(&mut log);
     }
-
-    fn type_string(&self) -> String {
-        let null_mark = if self.nullable && !matches!(self.typ, ConcreteType::Dynamic) {
-            "?"
-        } else {
-            ""
-        };
-        return match &self.typ {
-            ConcreteType::Int => "int".to_string(),
-            ConcreteType::Double => "double".to_string(),
-            ConcreteType::Bool => "bool".to_string(),
-            ConcreteType::String => "String".to_string(),
-            ConcreteType::Dynamic => "dynamic".to_string(),
-            ConcreteType::Enum(name) => name.to_string(),
-            ConcreteType::DateTime => "DateTime".to_string(),
-            ConcreteType::Custom(name) => name.clone(),
-        } + null_mark;
-    }
-
-    fn non_null_type_string(&self) -> String {
-        if self.nullable {
-            self.type_string().replace("?", "")
-        } else {
-            self.type_string()
+    let mut written = false;
+    let content = generated_content(&file, config, &mut log);
+    if config.check {
+        if let Some(content) = content
+            && !file.is_up_to_date(&content, config)
+        {
+            log += &format!("{}: error: out of date\n", file.generated_path(config));
+            has_error = true;
         }
-    }
-
-    fn is_custom(&self) -> bool {
-        matches!(self.typ, ConcreteType::Custom(_))
-    }
-
-    fn from_json_value(&self, key: String) -> String {
-        if self.is_custom() {
-            let factory = format!(
-                "{}.fromJson({key} as Map<String, dynamic>)",
-                self.non_null_type_string()
-            );
-            if self.nullable {
-                return format!("{key} == null ? null : {factory}");
-            }
-            return factory;
+    } else if config.stdout {
+        if let Some(content) = content {
+            output = format!("// ==> {} <==\n{content}\n", file.generated_path(config));
         }
-
-        let null_mark = if self.nullable { "?" } else { "" };
-        match &self.typ {
-            ConcreteType::Int => format!("({key} as num{null_mark}){null_mark}.toInt()"),
-            ConcreteType::Double => format!("({key} as num{null_mark}){null_mark}.toDouble()"),
-            ConcreteType::Enum(name) => {
-                format!(
-                    "{}{name}.values.singleWhere((v) => v.name == {key} as String)",
-                    if self.nullable {
-                        format!("{key} == null ? null : ")
-                    } else {
-                        "".to_string()
-                    }
-                )
-            }
-            ConcreteType::Dynamic => key,
-            ConcreteType::DateTime => format!(
-                "{}DateTime.parse({key} as String)",
-                if self.nullable {
-                    format!("{key} == null ? null : ")
-                } else {
-                    "".to_string()
+    } else if let Some(content) = content {
+        match file.write_file(&content, config) {
+            Ok(w) => {
+                written = w;
+                if config.verbose && !file.classes.is_empty() {
+                    let state = if w { "written" } else { "up to date" };
+                    log += &format!("{}: info: {state}\n", file.generated_path(config));
                 }
-            ),
-            ConcreteType::Bool | ConcreteType::String | ConcreteType::Custom(_) => {
-                format!("{key} as {}", self.type_string())
+            }
+            Err(e) => {
+                log += &format!("{}: error: {e}\n", file.generated_path(config));
+                has_error = true;
             }
         }
     }
-
-    fn to_json_value(&self, key: String) -> String {
-        let null_mark = if self.nullable { "?" } else { "" };
-        match self.typ {
-            ConcreteType::Int
-            | ConcreteType::Double
-            | ConcreteType::Bool
-            | ConcreteType::Dynamic
-            | ConcreteType::String => key,
-            ConcreteType::Enum(_) => format!("{key}{null_mark}.name"),
-            ConcreteType::DateTime => format!("{key}{null_mark}.toIso8601String()"),
-            ConcreteType::Custom(_) => format!("{key}{null_mark}.toJson()"),
-        }
+    Processed {
+        has_error,
+        has_warning,
+        classes: file.classes.len(),
+        written,
+        output,
+        log,
     }
 }
 
-#[derive(Debug)]
-enum DartType {
-    Concrete(Concrete),
-    GenericList { typ: Concrete, nullable: bool },
-}
-impl DartType {
-    fn from_string_and_options(name: String, options: &Option<FieldOptions>) -> Self {
-        let generic_list_regex = Regex::new(GENERIC_LIST_REGEX).unwrap();
-        let nullable = name.ends_with('?');
-        match generic_list_regex.captures(&name) {
-            Some(cap) => Self::GenericList {
-                typ: match options {
-                    Some(o) => match o.is_enum {
-                        true => Concrete::new(
-                            ConcreteType::Enum(cap[1].replace("?", "")),
-                            cap[1].ends_with("?"),
-                        ),
-                        false => Concrete::from_string(&cap[1]),
-                    },
-                    None => Concrete::from_string(&cap[1]),
-                },
-                nullable,
-            },
-            None => match options {
-                Some(o) => match o.is_enum {
-                    true => Self::Concrete(Concrete::new(
-                        ConcreteType::Enum(name.replace("?", "")),
-                        nullable,
-                    )),
-                    false => Self::Concrete(Concrete::from_string(&name)),
-                },
-                None => Self::Concrete(Concrete::from_string(&name)),
-            },
-        }
+/// Writes a Dart file exporting the given libraries, sorted by path
+fn write_index(index: &Path, sources: &[&str]) -> io::Result<()> {
+    let index_dir = normalize_path(index)
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    let mut exports: Vec<String> = sources
+        .iter()
+        .map(|source| relative_path(&index_dir, &normalize_path(Path::new(source))))
+        .collect();
+    exports.sort();
+    let mut lines = vec!["// dart format off\n".to_string()];
+    lines.extend(exports.iter().map(|e| format!("export '{e}';")));
+    if let Some(parent) = index.parent() {
+        fs::create_dir_all(parent)?;
     }
+    fs::write(index, lines.join("\n") + "\n")
+}
 
-    fn type_string(&self) -> String {
-        match self {
-            DartType::Concrete(concrete) => concrete.type_string(),
-            DartType::GenericList { typ, nullable } => {
+/// Source files matching the glob pattern, skipping generated files. The
+/// ignored files are logged in verbose mode with `log_skipped`, which is only
+/// set for the initial scan as watch mode scans the files again and again.
+fn dart_paths(patterns: &[String], config: &Config, log_skipped: bool) -> Vec<String> {
+    let generated_suffix = format!(".{}.dart", config.extension);
+    let mut dart_paths: Vec<String> = vec![];
+    // paths already added, as `dart_paths` keeps the order of the patterns
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    let patterns: Vec<String> = patterns
+        .iter()
+        .map(|pattern| {
+            // a directory stands for all the dart files in it
+            if Path::new(pattern).is_dir() {
                 format!(
-                    "List<{}>{}",
-                    typ.type_string(),
-                    if *nullable { "?" } else { "" }
+                    "{}/**/*.dart",
+                    Pattern::escape(pattern.trim_end_matches('/'))
                 )
+            } else {
+                pattern.clone()
+            }
+        })
+        .collect();
+    let entries = patterns
+        .iter()
+        .flat_map(|pattern| glob(pattern).expect("Failed to read glob pattern"));
+    for entry in entries {
+        match entry {
+            Err(_) => (),
+            Ok(path) => {
+                let p = path.to_string_lossy().to_string();
+                if log_skipped && config.verbose && config.ignore.is_ignored(&path) {
+                    eprintln!("{p}: info: skipped, matched by {IGNORE_FILE}");
+                }
+                if !p.ends_with(&generated_suffix)
+                    && !p.ends_with(".g.dart")
+                    && !p.ends_with(".freezed.dart")
+                    && !config.ignore.is_ignored(&path)
+                    // matched by several patterns
+                    && seen.insert(path.clone())
+                {
+                    dart_paths.push(p);
+                }
             }
         }
     }
-
-    fn non_null_type_string(&self) -> String {
-        match self {
-            DartType::Concrete(concrete) => concrete.non_null_type_string(),
-            DartType::GenericList { typ, nullable: _ } => format!("List<{}>", typ.type_string()),
-        }
-    }
+    dart_paths
 }
 
-#[derive(Debug)]
-struct FieldOptions {
-    key: Option<String>,
-    is_enum: bool,
-}
-impl FieldOptions {
-    fn new(key: Option<String>, is_enum: bool) -> Self {
-        Self { key, is_enum }
-    }
-
-    fn from_string(value: &str) -> Self {
-        let field_option_regex = Regex::new(FIELD_OPTIONS_REGEX).unwrap();
-        let mut key: Option<String> = None;
-        let mut is_enum = false;
-        for cap in field_option_regex.captures_iter(value) {
-            if let (Some(k), v) = (cap.name("key"), cap.name("value")) {
-                match v {
-                    Some(v) => {
-                        let mut value = v.as_str();
-                        if value.starts_with('"') && value.ends_with('"') {
-                            value = &value[1..value.len() - 1];
-                        }
-                        match k.as_str() {
-                            "key" => key = Some(value.to_string()),
-                            _ => {}
-                        }
-                    }
-                    None => match k.as_str() {
-                        "enum" => is_enum = true,
-                        _ => {}
-                    },
-                }
+/// Polls the files matching the pattern and regenerates the changed ones,
+/// waiting for rapid successive saves to settle first.
+///
+/// Polling is used instead of file system notifications, as with the `notify`
+/// crate, to keep the command free of that dependency. A poll only globs the
+/// patterns and reads the modification time of each source, which stays cheap
+/// for the few hundred files of a package, and it picks up new files and works
+/// the same on every platform and file system. Changes are seen within
+/// `WATCH_INTERVAL`, well under the debounce delay.
+fn watch(patterns: &[String], config: &Config) {
+    let modified_time = |path: &str| fs::metadata(path).and_then(|m| m.modified()).ok();
+    let mut modified: HashMap<String, SystemTime> = dart_paths(patterns, config, false)
+        .into_iter()
+        .filter_map(|path| modified_time(&path).map(|time| (path, time)))
+        .collect();
+    // changed files waiting for the debounce, with the time of the last change
+    let mut pending: HashMap<String, Instant> = HashMap::new();
+
+    // on stderr, as generated files are printed to stdout in stdout mode
+    eprintln!("Watching {} for changes...", patterns.join(", "));
+    loop {
+        thread::sleep(WATCH_INTERVAL);
+        for path in dart_paths(patterns, config, false) {
+            let Some(time) = modified_time(&path) else {
+                continue;
+            };
+            if modified.insert(path.clone(), time) != Some(time) {
+                pending.insert(path, Instant::now());
             }
         }
-        return FieldOptions::new(key, is_enum);
+        pending.retain(|path, changed_at| {
+            if changed_at.elapsed() < WATCH_DEBOUNCE {
+                return true;
+            }
+            // errors are reported with the diagnostics of the file
+            let result = process_file(path, config);
+            result.flush();
+            if result.written && !result.has_error {
+                eprintln!("regenerated {path}");
+            }
+            false
+        });
     }
 }
@@ -0,0 +1,85 @@
+//! Checks how the options of a `flu.conf` file combine with the command line.
+
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+const SOURCE: &str =
+    "// @flu\nabstract class _User {\n  const _User();\n\n  String? get name;\n}\n";
+
+/// Directory with the config file and a `lib/user.dart` source, unique per test
+fn project(name: &str, config: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("lib")).unwrap();
+    fs::write(dir.join("lib/user.dart"), SOURCE).unwrap();
+    fs::write(dir.join("flu.conf"), config).unwrap();
+    dir
+}
+
+fn run(dir: &PathBuf, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rust_dart_gen"))
+        .current_dir(dir)
+        .args(["--stdout"])
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn command_line_overrides_the_config_file() {
+    let dir = project(
+        "overrides",
+        "# defaults\nmerge: true\nomit_nulls: true # comment\nkey-case: \"snake\"\nindent: 4\n",
+    );
+    let from_file = stdout(&run(&dir, &[]));
+    assert!(from_file.contains("User merge(User other)"));
+    assert!(from_file.contains("if (name != null) "));
+    assert!(from_file.contains("\n        return User("));
+
+    let overridden = stdout(&run(&dir, &["--no-merge", "--indent=2", "-vp", "lib"]));
+    assert!(!overridden.contains("merge("));
+    assert!(overridden.contains("if (name != null) "));
+    assert!(overridden.contains("\n    return User("));
+}
+
+#[test]
+fn unsupported_syntax_is_an_error() {
+    for (config, error) in [
+        (
+            "path:\n  - lib\n",
+            "flu.conf:1: error: `path`: missing value\n",
+        ),
+        (
+            "path: [lib]\n",
+            "flu.conf:1: error: `path`: lists and maps are not supported, separate values with commas\n",
+        ),
+        (
+            "annotation: 'flu\n",
+            "flu.conf:1: error: `annotation`: unterminated ' quote\n",
+        ),
+        (
+            "merge: yes\n",
+            "flu.conf:1: error: `merge` must be `true` or `false`\n",
+        ),
+        (
+            "colour: red\n",
+            "flu.conf:1: error: unknown option `colour`\n",
+        ),
+    ] {
+        let output = run(&project("errors", config), &[]);
+        assert!(!output.status.success(), "{config}");
+        assert_eq!(String::from_utf8_lossy(&output.stderr), error, "{config}");
+    }
+}
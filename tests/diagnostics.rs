@@ -0,0 +1,171 @@
+//! Checks the diagnostics reported for invalid annotated classes.
+
+use rust_dart_gen::{Config, DartFile};
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+/// Diagnostics of a source file, one line each
+fn report(source: &str) -> (bool, String) {
+    let file = DartFile::from_string(source, "model.dart", &Config::default());
+    let mut log = String::new();
+    let has_error = file.report(&mut log);
+    (has_error, log)
+}
+
+/// Output of the command generating a source file read from stdin
+fn run(source: &str) -> Output {
+    run_command(Command::new(env!("CARGO_BIN_EXE_rust_dart_gen")), source)
+}
+
+/// Output of a command of the binary, adding the stdin options
+fn run_command(mut command: Command, source: &str) -> Output {
+    let mut child = command
+        .args(["--stdin", "--stdin-name", "model.dart"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn ignored_non_nullable_field_requires_a_default() {
+    let (has_error, log) = report(
+        "// @flu\nabstract class _User {\n  const _User();\n\n  // @flu ignore\n  int get count;\n}\n",
+    );
+    assert!(has_error);
+    assert_eq!(
+        log,
+        "model.dart:6: error: `count` is not in JSON, `ignore` requires a `default` for non-nullable fields\n"
+    );
+}
+
+#[test]
+fn ignored_field_with_a_default_or_nullable_is_valid() {
+    let (has_error, log) = report(
+        "// @flu\nabstract class _User {\n  const _User();\n\n  // @flu ignore default=0\n  int get count;\n  // @flu ignore\n  String? get note;\n}\n",
+    );
+    assert!(!has_error, "{log}");
+    assert_eq!(log, "");
+}
+
+#[test]
+fn unserializable_field_needs_converters() {
+    let (has_error, log) = report(
+        "// @flu\nabstract class _Job {\n  const _Job();\n\n  List<Completer<int>> get pending;\n  // @flu fromJson=decodeTimer toJson=encodeTimer\n  Timer get timer;\n  // @flu ignore\n  Stream<int>? get updates;\n}\n",
+    );
+    assert!(!has_error, "{log}");
+    assert_eq!(
+        log,
+        "model.dart:5: warning: skipped `pending`, `Completer` can't be serialized, convert it with `fromJson=` and `toJson=` functions or mark it with `ignore`\n"
+    );
+}
+
+#[test]
+fn warnings_fail_the_run() {
+    let output = run(
+        "// @flu\nabstract class _Job {\n  const _Job();\n\n  Completer<int> get pending;\n  int get id;\n}\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8_lossy(&output.stderr)
+            .contains("model.dart:5: warning: skipped `pending`")
+    );
+    // the code is still generated for the other fields
+    assert!(String::from_utf8_lossy(&output.stdout).contains("id: (json['id'] as num).toInt(),"));
+
+    let output = run("// @flu\nabstract class _Job {\n  const _Job();\n\n  int get id;\n}\n");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn failing_to_format_does_not_fail_the_run() {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_rust_dart_gen"));
+    // without `dart` to run
+    command.env("PATH", "").arg("--format-on");
+    let output = run_command(
+        command,
+        "// @flu\nabstract class _Job {\n  const _Job();\n\n  int get id;\n}\n",
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.starts_with("model.flu.dart: warning: not formatted, can't run `dart format`"),
+        "{stderr}"
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("class Job extends _Job"));
+}
+
+#[test]
+fn duplicate_field_is_an_error() {
+    let source = "// @flu\nabstract class _User {\n  const _User();\n\n  int get id;\n  String get name;\n  int get id;\n}\n";
+    let (has_error, log) = report(source);
+    assert!(has_error);
+    assert_eq!(log, "model.dart:7: error: duplicate field `id`\n");
+    let config = Config::default();
+    let generated = DartFile::from_string(source, "model.dart", &config)
+        .generate_string(&config)
+        .unwrap_or_default();
+    assert!(
+        generated.matches("final int id;").count() <= 1,
+        "{generated}"
+    );
+}
+
+#[test]
+fn const_constructor_of_a_mutable_class_is_ignored() {
+    let (has_error, log) = report(
+        "// @flu\n// @flu: mutable\nabstract class _Counter {\n  const _Counter();\n\n  int get value;\n}\n",
+    );
+    assert!(!has_error, "{log}");
+    assert_eq!(
+        log,
+        "model.dart:3: warning: `const _Counter();` is ignored, the generated constructor of a mutable class is not const\n"
+    );
+    let (_, log) =
+        report("// @flu\n// @flu: mutable\nabstract class _Counter {\n  int get value;\n}\n");
+    assert_eq!(log, "");
+}
+
+#[test]
+fn class_map_key_is_an_error() {
+    let (has_error, log) = report(
+        "// @flu\nabstract class _Scores {\n  const _Scores();\n\n  Map<User, int> get byUser;\n  // @flu enum\n  Map<Status, int> get byStatus;\n}\n",
+    );
+    assert!(has_error);
+    assert_eq!(
+        log,
+        "model.dart:5: error: `User` map keys can't be decoded from JSON object keys\n"
+    );
+}
+
+#[test]
+fn base_name_same_as_generated_name_is_an_error() {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_rust_dart_gen"));
+    command.args(["--base-prefix=", "--base-suffix="]);
+    let output = run_command(
+        command,
+        "// @flu\nabstract class User {\n  int get id;\n}\n",
+    );
+    assert_eq!(output.status.code(), Some(2));
+    assert!(
+        String::from_utf8_lossy(&output.stderr).starts_with(
+            "error: `--base-prefix` and `--base-suffix` can't both be empty, generated classes would extend themselves"
+        )
+    );
+    assert!(output.stdout.is_empty());
+}
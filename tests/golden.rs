@@ -0,0 +1,85 @@
+//! Compares the code generated for the `tests/golden/*.dart` fixtures with the
+//! checked-in `*.flu.dart` files next to them, and their diagnostics with the
+//! `*.log` files. A file is missing when there is nothing to generate or report.
+//!
+//! A fixture can start with a `// args: ...` line of extra command line options.
+//! Run with `UPDATE_GOLDEN=1` to rewrite the golden files after an intended change.
+
+use clap::Parser;
+use rust_dart_gen::{Args, DartFile};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+const ARGS_PREFIX: &str = "// args: ";
+
+/// Source fixtures, skipping the golden files
+fn fixtures(dir: &Path) -> Vec<PathBuf> {
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.ends_with(".dart") && !name.ends_with(".flu.dart")
+        })
+        .collect();
+    fixtures.sort();
+    fixtures
+}
+
+/// Generated code and diagnostics of a fixture, as the command reads it from stdin
+fn generate(fixture: &Path) -> (Option<String>, Option<String>) {
+    let source = fs::read_to_string(fixture).unwrap();
+    let args: Vec<&str> = source
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix(ARGS_PREFIX))
+        .map(|args| args.split_whitespace().collect())
+        .unwrap_or_default();
+    let config = Args::parse_from(["rust_dart_gen"].into_iter().chain(args))
+        .config()
+        .unwrap();
+    let name = fixture.file_name().unwrap().to_string_lossy().to_string();
+    let file = DartFile::from_string(&source, &name, &config);
+    let mut log = String::new();
+    file.report(&mut log);
+    let log = Some(log).filter(|log| !log.is_empty());
+    (file.generate_string(&config), log)
+}
+
+/// Whether the golden file has the expected content, rewriting it in update mode
+fn matches(golden: &Path, expected: Option<&str>, update: bool) -> bool {
+    if update {
+        match expected {
+            Some(content) => fs::write(golden, content).unwrap(),
+            None if golden.exists() => fs::remove_file(golden).unwrap(),
+            None => {}
+        }
+        return true;
+    }
+    fs::read_to_string(golden).ok().as_deref() == expected
+}
+
+#[test]
+fn generated_code_matches_golden_files() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+    let mut mismatches = vec![];
+    for fixture in fixtures(&dir) {
+        let (generated, log) = generate(&fixture);
+        for (golden, expected) in [
+            (fixture.with_extension("flu.dart"), generated),
+            (fixture.with_extension("log"), log),
+        ] {
+            if !matches(&golden, expected.as_deref(), update) {
+                mismatches.push(golden.display().to_string());
+            }
+        }
+    }
+    assert!(
+        mismatches.is_empty(),
+        "generated code differs from {}, run with UPDATE_GOLDEN=1 to update golden files",
+        mismatches.join(", ")
+    );
+}
@@ -0,0 +1,12 @@
+// args: --annotate-keys --key-case snake
+part 'annotate_keys.flu.dart';
+
+// @flu
+abstract class _Post {
+  String get title;
+  DateTime get createdAt;
+  // @flu path="author.name"
+  String get authorName;
+  // @flu ignore
+  bool? get selected;
+}
@@ -0,0 +1,86 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'annotate_keys.dart';
+
+const _sentinel = Object();
+
+class Post extends _Post {
+  Post({
+    required this.title,
+    required this.createdAt,
+    required this.authorName,
+    required this.selected,
+  });
+
+  factory Post.fromJson(Map<String, dynamic> json) {
+    return Post(
+      title: json['title'] as String,
+      createdAt: DateTime.parse(json['created_at'] as String),
+      authorName: (json['author'] as Map<String, dynamic>?)?['name'] as String,
+      selected: null,
+    );
+  }
+
+  static List<Post> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Post.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String title; // json: 'title'
+
+  @override
+  final DateTime createdAt; // json: 'created_at'
+
+  @override
+  final String authorName; // json: 'author.name'
+
+  @override
+  final bool? selected;
+
+  Map<String, dynamic> toJson() => {
+    'title': title,
+    'created_at': createdAt.toIso8601String(),
+    'author': {
+      'name': authorName,
+    },
+  };
+
+  Post copyWith({
+    String? title,
+    DateTime? createdAt,
+    String? authorName,
+    Object? selected = _sentinel,
+  }) => Post(
+    title: title ?? this.title,
+    createdAt: createdAt ?? this.createdAt,
+    authorName: authorName ?? this.authorName,
+    selected: identical(selected, _sentinel) ? this.selected : selected as bool?,
+  );
+
+  @override
+  String toString() => 'Post('
+    'title: $title '
+    'createdAt: $createdAt '
+    'authorName: $authorName '
+    'selected: $selected '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Post
+      && other.title == title
+      && other.createdAt == createdAt
+      && other.authorName == authorName
+      && other.selected == selected;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    title,
+    createdAt,
+    authorName,
+    selected,
+  );
+}
@@ -0,0 +1,11 @@
+// args: --verbose
+part 'arrow_getters.flu.dart';
+
+// @flu
+abstract class _Person {
+  String get first;
+  String get full => '$first $last';
+  String get last;
+  bool get isAnonymous => first.isEmpty && last.isEmpty;
+  int get age;
+}
@@ -0,0 +1,72 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'arrow_getters.dart';
+
+class Person extends _Person {
+  Person({
+    required this.first,
+    required this.last,
+    required this.age,
+  });
+
+  factory Person.fromJson(Map<String, dynamic> json) {
+    return Person(
+      first: json['first'] as String,
+      last: json['last'] as String,
+      age: (json['age'] as num).toInt(),
+    );
+  }
+
+  static List<Person> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Person.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String first;
+
+  @override
+  final String last;
+
+  @override
+  final int age;
+
+  Map<String, dynamic> toJson() => {
+    'first': first,
+    'last': last,
+    'age': age,
+  };
+
+  Person copyWith({
+    String? first,
+    String? last,
+    int? age,
+  }) => Person(
+    first: first ?? this.first,
+    last: last ?? this.last,
+    age: age ?? this.age,
+  );
+
+  @override
+  String toString() => 'Person('
+    'first: $first '
+    'last: $last '
+    'age: $age '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Person
+      && other.first == first
+      && other.last == last
+      && other.age == age;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    first,
+    last,
+    age,
+  );
+}
@@ -0,0 +1,13 @@
+// args: --assert-required
+part 'assert_required.flu.dart';
+
+// @flu
+abstract class _Order {
+  // @flu key="order_id"
+  String get id;
+  int get quantity;
+  // @flu default=false
+  bool get paid;
+  String? get note;
+  Customer get customer;
+}
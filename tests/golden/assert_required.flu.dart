@@ -0,0 +1,96 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'assert_required.dart';
+
+const _sentinel = Object();
+
+class Order extends _Order {
+  Order({
+    required this.id,
+    required this.quantity,
+    this.paid = false,
+    required this.note,
+    required this.customer,
+  });
+
+  factory Order.fromJson(Map<String, dynamic> json) {
+    return Order(
+      id: json['order_id'] == null ? throw const FormatException('Missing required field: order_id in Order') : json['order_id'] as String,
+      quantity: json['quantity'] == null ? throw const FormatException('Missing required field: quantity in Order') : (json['quantity'] as num).toInt(),
+      paid: json['paid'] == null ? false : json['paid'] as bool,
+      note: json['note'] as String?,
+      customer: json['customer'] == null ? throw const FormatException('Missing required field: customer in Order') : Customer.fromJson(json['customer'] as Map<String, dynamic>),
+    );
+  }
+
+  static List<Order> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Order.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  @override
+  final int quantity;
+
+  @override
+  final bool paid;
+
+  @override
+  final String? note;
+
+  @override
+  final Customer customer;
+
+  Map<String, dynamic> toJson() => {
+    'order_id': id,
+    'quantity': quantity,
+    'paid': paid,
+    'note': note,
+    'customer': customer.toJson(),
+  };
+
+  Order copyWith({
+    String? id,
+    int? quantity,
+    bool? paid,
+    Object? note = _sentinel,
+    Customer? customer,
+  }) => Order(
+    id: id ?? this.id,
+    quantity: quantity ?? this.quantity,
+    paid: paid ?? this.paid,
+    note: identical(note, _sentinel) ? this.note : note as String?,
+    customer: customer ?? this.customer,
+  );
+
+  @override
+  String toString() => 'Order('
+    'id: $id '
+    'quantity: $quantity '
+    'paid: $paid '
+    'note: $note '
+    'customer: $customer '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Order
+      && other.id == id
+      && other.quantity == quantity
+      && other.paid == paid
+      && other.note == note
+      && other.customer == customer;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    quantity,
+    paid,
+    note,
+    customer,
+  );
+}
@@ -0,0 +1,23 @@
+part 'basic.flu.dart';
+
+enum Role { admin, member }
+
+// @flu
+abstract class _User {
+  const _User();
+
+  /// Unique id of the user.
+  int get id;
+  String get name;
+  String? get nickname;
+  double get score;
+  bool get active;
+  DateTime get createdAt;
+  List<String> get tags;
+  Set<int>? get groups;
+  Map<String, dynamic> get metadata;
+  // @flu enum
+  Role get role;
+  // @flu key="home_address"
+  Address? get address;
+}
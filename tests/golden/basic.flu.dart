@@ -0,0 +1,165 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'basic.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class User extends _User {
+  const User({
+    required this.id,
+    required this.name,
+    required this.nickname,
+    required this.score,
+    required this.active,
+    required this.createdAt,
+    required this.tags,
+    required this.groups,
+    required this.metadata,
+    required this.role,
+    required this.address,
+  });
+
+  factory User.fromJson(Map<String, dynamic> json) {
+    return User(
+      id: (json['id'] as num).toInt(),
+      name: json['name'] as String,
+      nickname: json['nickname'] as String?,
+      score: (json['score'] as num).toDouble(),
+      active: json['active'] as bool,
+      createdAt: DateTime.parse(json['createdAt'] as String),
+      tags: (json['tags'] as List).map((e) => e as String).toList(),
+      groups: (json['groups'] as List?)?.map((e) => (e as num).toInt()).toSet(),
+      metadata: json['metadata'] as Map<String, dynamic>,
+      role: Role.values.singleWhere((v) => v.name == json['role'] as String),
+      address: json['home_address'] == null ? null : Address.fromJson(json['home_address'] as Map<String, dynamic>),
+    );
+  }
+
+  static List<User> fromJsonList(List<dynamic> json) =>
+      json.map((e) => User.fromJson(e as Map<String, dynamic>)).toList();
+
+  /// Unique id of the user.
+  @override
+  final int id;
+
+  @override
+  final String name;
+
+  @override
+  final String? nickname;
+
+  @override
+  final double score;
+
+  @override
+  final bool active;
+
+  @override
+  final DateTime createdAt;
+
+  @override
+  final List<String> tags;
+
+  @override
+  final Set<int>? groups;
+
+  @override
+  final Map<String, dynamic> metadata;
+
+  @override
+  final Role role;
+
+  @override
+  final Address? address;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+    'name': name,
+    'nickname': nickname,
+    'score': score,
+    'active': active,
+    'createdAt': createdAt.toIso8601String(),
+    'tags': tags,
+    'groups': groups?.toList(),
+    'metadata': metadata,
+    'role': role.name,
+    'home_address': address?.toJson(),
+  };
+
+  User copyWith({
+    int? id,
+    String? name,
+    Object? nickname = _sentinel,
+    double? score,
+    bool? active,
+    DateTime? createdAt,
+    List<String>? tags,
+    Object? groups = _sentinel,
+    Map<String, dynamic>? metadata,
+    Role? role,
+    Object? address = _sentinel,
+  }) => User(
+    id: id ?? this.id,
+    name: name ?? this.name,
+    nickname: identical(nickname, _sentinel) ? this.nickname : nickname as String?,
+    score: score ?? this.score,
+    active: active ?? this.active,
+    createdAt: createdAt ?? this.createdAt,
+    tags: tags ?? this.tags,
+    groups: identical(groups, _sentinel) ? this.groups : groups as Set<int>?,
+    metadata: metadata ?? this.metadata,
+    role: role ?? this.role,
+    address: identical(address, _sentinel) ? this.address : address as Address?,
+  );
+
+  @override
+  String toString() => 'User('
+    'id: $id '
+    'name: $name '
+    'nickname: $nickname '
+    'score: $score '
+    'active: $active '
+    'createdAt: $createdAt '
+    'tags: $tags '
+    'groups: $groups '
+    'metadata: $metadata '
+    'role: $role '
+    'address: $address '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is User
+      && other.id == id
+      && other.name == name
+      && other.nickname == nickname
+      && other.score == score
+      && other.active == active
+      && other.createdAt == createdAt
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && const DeepCollectionEquality().equals(other.groups, groups)
+      && const DeepCollectionEquality().equals(other.metadata, metadata)
+      && other.role == role
+      && other.address == address;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    name,
+    nickname,
+    score,
+    active,
+    createdAt,
+    const DeepCollectionEquality().hash(tags),
+    const DeepCollectionEquality().hash(groups),
+    const DeepCollectionEquality().hash(metadata),
+    role,
+    address,
+  );
+}
@@ -0,0 +1,10 @@
+part 'big_ints.flu.dart';
+
+// @flu
+abstract class _Account {
+  const _Account();
+
+  BigInt get id;
+  BigInt? get parentId;
+  List<BigInt> get transfers;
+}
@@ -0,0 +1,76 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'big_ints.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Account extends _Account {
+  const Account({
+    required this.id,
+    required this.parentId,
+    required this.transfers,
+  });
+
+  factory Account.fromJson(Map<String, dynamic> json) {
+    return Account(
+      id: BigInt.parse(json['id'] as String),
+      parentId: json['parentId'] == null ? null : BigInt.parse(json['parentId'] as String),
+      transfers: (json['transfers'] as List).map((e) => BigInt.parse(e as String)).toList(),
+    );
+  }
+
+  static List<Account> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Account.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final BigInt id;
+
+  @override
+  final BigInt? parentId;
+
+  @override
+  final List<BigInt> transfers;
+
+  Map<String, dynamic> toJson() => {
+    'id': id.toString(),
+    'parentId': parentId?.toString(),
+    'transfers': transfers.map((e) => e.toString()).toList(),
+  };
+
+  Account copyWith({
+    BigInt? id,
+    Object? parentId = _sentinel,
+    List<BigInt>? transfers,
+  }) => Account(
+    id: id ?? this.id,
+    parentId: identical(parentId, _sentinel) ? this.parentId : parentId as BigInt?,
+    transfers: transfers ?? this.transfers,
+  );
+
+  @override
+  String toString() => 'Account('
+    'id: $id '
+    'parentId: $parentId '
+    'transfers: $transfers '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Account
+      && other.id == id
+      && other.parentId == parentId
+      && const DeepCollectionEquality().equals(other.transfers, transfers);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    parentId,
+    const DeepCollectionEquality().hash(transfers),
+  );
+}
@@ -0,0 +1,15 @@
+part 'block_comments.flu.dart';
+
+// @flu
+abstract class _Counter {
+  const _Counter();
+
+  int get count;
+  /* a disabled field
+  int get removed;
+  } */
+  void reset() {
+    /* } /* nested } */ still a comment } */
+  }
+  int get step; /* trailing { */
+}
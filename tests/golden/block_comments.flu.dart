@@ -0,0 +1,61 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'block_comments.dart';
+
+class Counter extends _Counter {
+  const Counter({
+    required this.count,
+    required this.step,
+  });
+
+  factory Counter.fromJson(Map<String, dynamic> json) {
+    return Counter(
+      count: (json['count'] as num).toInt(),
+      step: (json['step'] as num).toInt(),
+    );
+  }
+
+  static List<Counter> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Counter.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int count;
+
+  @override
+  final int step;
+
+  Map<String, dynamic> toJson() => {
+    'count': count,
+    'step': step,
+  };
+
+  Counter copyWith({
+    int? count,
+    int? step,
+  }) => Counter(
+    count: count ?? this.count,
+    step: step ?? this.step,
+  );
+
+  @override
+  String toString() => 'Counter('
+    'count: $count '
+    'step: $step '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Counter
+      && other.count == count
+      && other.step == step;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    count,
+    step,
+  );
+}
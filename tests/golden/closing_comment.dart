@@ -0,0 +1,10 @@
+part 'closing_comment.flu.dart';
+
+// @flu
+abstract class _First {
+  String get name;
+} // end of _First
+// @flu
+abstract class _Second {
+  int get count;
+}
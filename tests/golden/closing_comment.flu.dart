@@ -0,0 +1,91 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'closing_comment.dart';
+
+class First extends _First {
+  First({
+    required this.name,
+  });
+
+  factory First.fromJson(Map<String, dynamic> json) {
+    return First(
+      name: json['name'] as String,
+    );
+  }
+
+  static List<First> fromJsonList(List<dynamic> json) =>
+      json.map((e) => First.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+  };
+
+  First copyWith({
+    String? name,
+  }) => First(
+    name: name ?? this.name,
+  );
+
+  @override
+  String toString() => 'First('
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is First
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => name.hashCode;
+}
+
+class Second extends _Second {
+  Second({
+    required this.count,
+  });
+
+  factory Second.fromJson(Map<String, dynamic> json) {
+    return Second(
+      count: (json['count'] as num).toInt(),
+    );
+  }
+
+  static List<Second> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Second.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int count;
+
+  Map<String, dynamic> toJson() => {
+    'count': count,
+  };
+
+  Second copyWith({
+    int? count,
+  }) => Second(
+    count: count ?? this.count,
+  );
+
+  @override
+  String toString() => 'Second('
+    'count: $count '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Second
+      && other.count == count;
+  }
+
+  @override
+  int get hashCode => count.hashCode;
+}
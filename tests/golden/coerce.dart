@@ -0,0 +1,14 @@
+part 'coerce.flu.dart';
+
+// @flu
+abstract class _Counts {
+  // @flu coerce
+  int get count;
+  // @flu coerce
+  double? get ratio;
+  // @flu coerce strictInt
+  int get exact;
+  // @flu coerce
+  List<num> get values;
+  int get strict;
+}
@@ -0,0 +1,98 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'coerce.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Counts extends _Counts {
+  Counts({
+    required this.count,
+    required this.ratio,
+    required this.exact,
+    required this.values,
+    required this.strict,
+  });
+
+  factory Counts.fromJson(Map<String, dynamic> json) {
+    return Counts(
+      count: num.parse(json['count'].toString()).toInt(),
+      ratio: json['ratio'] == null ? null : num.parse(json['ratio'].toString()).toDouble(),
+      exact: switch (num.parse(json['exact'].toString())) { final n when n == n.toInt() => n.toInt(), final n => throw FormatException('Expected an integer', n) },
+      values: (json['values'] as List).map((e) => num.parse(e.toString())).toList(),
+      strict: (json['strict'] as num).toInt(),
+    );
+  }
+
+  static List<Counts> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Counts.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int count;
+
+  @override
+  final double? ratio;
+
+  @override
+  final int exact;
+
+  @override
+  final List<num> values;
+
+  @override
+  final int strict;
+
+  Map<String, dynamic> toJson() => {
+    'count': count,
+    'ratio': ratio,
+    'exact': exact,
+    'values': values,
+    'strict': strict,
+  };
+
+  Counts copyWith({
+    int? count,
+    Object? ratio = _sentinel,
+    int? exact,
+    List<num>? values,
+    int? strict,
+  }) => Counts(
+    count: count ?? this.count,
+    ratio: identical(ratio, _sentinel) ? this.ratio : ratio as double?,
+    exact: exact ?? this.exact,
+    values: values ?? this.values,
+    strict: strict ?? this.strict,
+  );
+
+  @override
+  String toString() => 'Counts('
+    'count: $count '
+    'ratio: $ratio '
+    'exact: $exact '
+    'values: $values '
+    'strict: $strict '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Counts
+      && other.count == count
+      && other.ratio == ratio
+      && other.exact == exact
+      && const DeepCollectionEquality().equals(other.values, values)
+      && other.strict == strict;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    count,
+    ratio,
+    exact,
+    const DeepCollectionEquality().hash(values),
+    strict,
+  );
+}
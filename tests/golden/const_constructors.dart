@@ -0,0 +1,22 @@
+part 'const_constructors.flu.dart';
+
+// @flu
+abstract class _Exact {
+  const _Exact();
+
+  String get a;
+}
+
+// @flu
+abstract class _Spaced {
+  const _Spaced( ) ;
+
+  String get a;
+}
+
+// @flu
+abstract class _Indented {
+    const   _Indented();
+
+  String get a;
+}
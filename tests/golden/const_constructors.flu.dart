@@ -0,0 +1,134 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'const_constructors.dart';
+
+class Exact extends _Exact {
+  const Exact({
+    required this.a,
+  });
+
+  factory Exact.fromJson(Map<String, dynamic> json) {
+    return Exact(
+      a: json['a'] as String,
+    );
+  }
+
+  static List<Exact> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Exact.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String a;
+
+  Map<String, dynamic> toJson() => {
+    'a': a,
+  };
+
+  Exact copyWith({
+    String? a,
+  }) => Exact(
+    a: a ?? this.a,
+  );
+
+  @override
+  String toString() => 'Exact('
+    'a: $a '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Exact
+      && other.a == a;
+  }
+
+  @override
+  int get hashCode => a.hashCode;
+}
+
+class Spaced extends _Spaced {
+  const Spaced({
+    required this.a,
+  });
+
+  factory Spaced.fromJson(Map<String, dynamic> json) {
+    return Spaced(
+      a: json['a'] as String,
+    );
+  }
+
+  static List<Spaced> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Spaced.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String a;
+
+  Map<String, dynamic> toJson() => {
+    'a': a,
+  };
+
+  Spaced copyWith({
+    String? a,
+  }) => Spaced(
+    a: a ?? this.a,
+  );
+
+  @override
+  String toString() => 'Spaced('
+    'a: $a '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Spaced
+      && other.a == a;
+  }
+
+  @override
+  int get hashCode => a.hashCode;
+}
+
+class Indented extends _Indented {
+  const Indented({
+    required this.a,
+  });
+
+  factory Indented.fromJson(Map<String, dynamic> json) {
+    return Indented(
+      a: json['a'] as String,
+    );
+  }
+
+  static List<Indented> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Indented.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String a;
+
+  Map<String, dynamic> toJson() => {
+    'a': a,
+  };
+
+  Indented copyWith({
+    String? a,
+  }) => Indented(
+    a: a ?? this.a,
+  );
+
+  @override
+  String toString() => 'Indented('
+    'a: $a '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Indented
+      && other.a == a;
+  }
+
+  @override
+  int get hashCode => a.hashCode;
+}
@@ -0,0 +1,13 @@
+part 'converters.flu.dart';
+
+// @flu
+abstract class _Theme {
+  const _Theme();
+
+  // @flu fromJson=_decodeColor toJson=_encodeColor
+  Color get primary;
+  // @flu fromJson=_decodeColor toJson=_encodeColor
+  Color? get accent;
+  // @flu fromJson=parseFlags
+  Set<String> get flags;
+}
@@ -0,0 +1,76 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'converters.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Theme extends _Theme {
+  const Theme({
+    required this.primary,
+    required this.accent,
+    required this.flags,
+  });
+
+  factory Theme.fromJson(Map<String, dynamic> json) {
+    return Theme(
+      primary: _decodeColor(json['primary']),
+      accent: _decodeColor(json['accent']),
+      flags: parseFlags(json['flags']),
+    );
+  }
+
+  static List<Theme> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Theme.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Color primary;
+
+  @override
+  final Color? accent;
+
+  @override
+  final Set<String> flags;
+
+  Map<String, dynamic> toJson() => {
+    'primary': _encodeColor(primary),
+    'accent': _encodeColor(accent),
+    'flags': flags.toList(),
+  };
+
+  Theme copyWith({
+    Color? primary,
+    Object? accent = _sentinel,
+    Set<String>? flags,
+  }) => Theme(
+    primary: primary ?? this.primary,
+    accent: identical(accent, _sentinel) ? this.accent : accent as Color?,
+    flags: flags ?? this.flags,
+  );
+
+  @override
+  String toString() => 'Theme('
+    'primary: $primary '
+    'accent: $accent '
+    'flags: $flags '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Theme
+      && other.primary == primary
+      && other.accent == accent
+      && const DeepCollectionEquality().equals(other.flags, flags);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    primary,
+    accent,
+    const DeepCollectionEquality().hash(flags),
+  );
+}
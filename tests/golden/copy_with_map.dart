@@ -0,0 +1,15 @@
+// args: --copy-with-map
+part 'copy_with_map.flu.dart';
+
+// @flu
+abstract class _Profile {
+  // @flu key="display_name"
+  String get name;
+  int? get age;
+  // @flu path="address.city"
+  String get city;
+  // @flu default=false
+  bool get verified;
+  // @flu copyWith=false
+  String get id;
+}
@@ -0,0 +1,105 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'copy_with_map.dart';
+
+const _sentinel = Object();
+
+class Profile extends _Profile {
+  Profile({
+    required this.name,
+    required this.age,
+    required this.city,
+    this.verified = false,
+    required this.id,
+  });
+
+  factory Profile.fromJson(Map<String, dynamic> json) {
+    return Profile(
+      name: json['display_name'] as String,
+      age: (json['age'] as num?)?.toInt(),
+      city: (json['address'] as Map<String, dynamic>?)?['city'] as String,
+      verified: json['verified'] == null ? false : json['verified'] as bool,
+      id: json['id'] as String,
+    );
+  }
+
+  static List<Profile> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Profile.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  @override
+  final int? age;
+
+  @override
+  final String city;
+
+  @override
+  final bool verified;
+
+  @override
+  final String id;
+
+  Map<String, dynamic> toJson() => {
+    'display_name': name,
+    'age': age,
+    'address': {
+      'city': city,
+    },
+    'verified': verified,
+    'id': id,
+  };
+
+  Profile copyWith({
+    String? name,
+    Object? age = _sentinel,
+    String? city,
+    bool? verified,
+  }) => Profile(
+    name: name ?? this.name,
+    age: identical(age, _sentinel) ? this.age : age as int?,
+    city: city ?? this.city,
+    verified: verified ?? this.verified,
+    id: this.id,
+  );
+
+  Profile copyWithMap(Map<String, dynamic> json) => Profile(
+    name: json.containsKey('display_name') ? json['display_name'] as String : this.name,
+    age: json.containsKey('age') ? (json['age'] as num?)?.toInt() : this.age,
+    city: ((json['address'] as Map<String, dynamic>?)?.containsKey('city') ?? false) ? (json['address'] as Map<String, dynamic>?)?['city'] as String : this.city,
+    verified: json.containsKey('verified') ? (json['verified'] == null ? false : json['verified'] as bool) : this.verified,
+    id: this.id,
+  );
+
+  @override
+  String toString() => 'Profile('
+    'name: $name '
+    'age: $age '
+    'city: $city '
+    'verified: $verified '
+    'id: $id '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Profile
+      && other.name == name
+      && other.age == age
+      && other.city == city
+      && other.verified == verified
+      && other.id == id;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    age,
+    city,
+    verified,
+    id,
+  );
+}
@@ -0,0 +1,18 @@
+part 'copy_with_override.flu.dart';
+
+// @flu
+abstract class _Animal {
+  String get name;
+
+  _Animal copyWith({String? name});
+}
+
+// @flu
+abstract class _Dog extends _Animal {
+  bool get goodBoy;
+}
+
+// @flu
+abstract class _Plant {
+  String get name;
+}
@@ -0,0 +1,149 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'copy_with_override.dart';
+
+class Animal extends _Animal {
+  Animal({
+    required this.name,
+  });
+
+  factory Animal.fromJson(Map<String, dynamic> json) {
+    return Animal(
+      name: json['name'] as String,
+    );
+  }
+
+  static List<Animal> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Animal.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+  };
+
+  @override
+  Animal copyWith({
+    String? name,
+  }) => Animal(
+    name: name ?? this.name,
+  );
+
+  @override
+  String toString() => 'Animal('
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Animal
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => name.hashCode;
+}
+
+class Dog extends _Dog {
+  Dog({
+    required this.name,
+    required this.goodBoy,
+  });
+
+  factory Dog.fromJson(Map<String, dynamic> json) {
+    return Dog(
+      name: json['name'] as String,
+      goodBoy: json['goodBoy'] as bool,
+    );
+  }
+
+  static List<Dog> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Dog.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  @override
+  final bool goodBoy;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+    'goodBoy': goodBoy,
+  };
+
+  @override
+  Dog copyWith({
+    String? name,
+    bool? goodBoy,
+  }) => Dog(
+    name: name ?? this.name,
+    goodBoy: goodBoy ?? this.goodBoy,
+  );
+
+  @override
+  String toString() => 'Dog('
+    'name: $name '
+    'goodBoy: $goodBoy '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Dog
+      && other.name == name
+      && other.goodBoy == goodBoy;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    goodBoy,
+  );
+}
+
+class Plant extends _Plant {
+  Plant({
+    required this.name,
+  });
+
+  factory Plant.fromJson(Map<String, dynamic> json) {
+    return Plant(
+      name: json['name'] as String,
+    );
+  }
+
+  static List<Plant> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Plant.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+  };
+
+  Plant copyWith({
+    String? name,
+  }) => Plant(
+    name: name ?? this.name,
+  );
+
+  @override
+  String toString() => 'Plant('
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Plant
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => name.hashCode;
+}
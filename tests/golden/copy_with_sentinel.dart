@@ -0,0 +1,11 @@
+// args: --copywith-style sentinel
+part 'copy_with_sentinel.flu.dart';
+
+// `copyWith(links: null)` clears the list, `copyWith(links: [])` empties it
+// and `copyWith()` keeps it
+// @flu
+abstract class _Profile {
+  String get name;
+  List<String>? get links;
+  List<String> get tags;
+}
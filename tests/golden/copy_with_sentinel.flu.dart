@@ -0,0 +1,76 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'copy_with_sentinel.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Profile extends _Profile {
+  Profile({
+    required this.name,
+    required this.links,
+    required this.tags,
+  });
+
+  factory Profile.fromJson(Map<String, dynamic> json) {
+    return Profile(
+      name: json['name'] as String,
+      links: (json['links'] as List?)?.map((e) => e as String).toList(),
+      tags: (json['tags'] as List).map((e) => e as String).toList(),
+    );
+  }
+
+  static List<Profile> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Profile.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  @override
+  final List<String>? links;
+
+  @override
+  final List<String> tags;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+    'links': links,
+    'tags': tags,
+  };
+
+  Profile copyWith({
+    String? name,
+    Object? links = _sentinel,
+    List<String>? tags,
+  }) => Profile(
+    name: name ?? this.name,
+    links: identical(links, _sentinel) ? this.links : links as List<String>?,
+    tags: tags ?? this.tags,
+  );
+
+  @override
+  String toString() => 'Profile('
+    'name: $name '
+    'links: $links '
+    'tags: $tags '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Profile
+      && other.name == name
+      && const DeepCollectionEquality().equals(other.links, links)
+      && const DeepCollectionEquality().equals(other.tags, tags);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    const DeepCollectionEquality().hash(links),
+    const DeepCollectionEquality().hash(tags),
+  );
+}
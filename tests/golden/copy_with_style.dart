@@ -0,0 +1,9 @@
+// args: --copywith-style valuegetter --optional-nullable
+part 'copy_with_style.flu.dart';
+
+// @flu
+abstract class _Profile {
+  String get name;
+  String? get bio;
+  List<String>? get links;
+}
@@ -0,0 +1,76 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'copy_with_style.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+// requires `import 'package:flutter/foundation.dart';` in the library
+
+class Profile extends _Profile {
+  Profile({
+    required this.name,
+    this.bio,
+    this.links,
+  });
+
+  factory Profile.fromJson(Map<String, dynamic> json) {
+    return Profile(
+      name: json['name'] as String,
+      bio: json['bio'] as String?,
+      links: (json['links'] as List?)?.map((e) => e as String).toList(),
+    );
+  }
+
+  static List<Profile> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Profile.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  @override
+  final String? bio;
+
+  @override
+  final List<String>? links;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+    'bio': bio,
+    'links': links,
+  };
+
+  Profile copyWith({
+    String? name,
+    ValueGetter<String?>? bio,
+    ValueGetter<List<String>?>? links,
+  }) => Profile(
+    name: name ?? this.name,
+    bio: bio != null ? bio() : this.bio,
+    links: links != null ? links() : this.links,
+  );
+
+  @override
+  String toString() => 'Profile('
+    'name: $name '
+    'bio: $bio '
+    'links: $links '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Profile
+      && other.name == name
+      && other.bio == bio
+      && const DeepCollectionEquality().equals(other.links, links);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    bio,
+    const DeepCollectionEquality().hash(links),
+  );
+}
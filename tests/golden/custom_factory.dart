@@ -0,0 +1,12 @@
+part 'custom_factory.flu.dart';
+
+// @flu
+abstract class _Order {
+  // @flu factory="deserialize" encode="serialize"
+  Money get total;
+  // @flu factory="deserialize" encode="serialize"
+  Money? get discount;
+  // @flu factory="deserialize" encode="serialize"
+  List<Money> get payments;
+  Money get fee;
+}
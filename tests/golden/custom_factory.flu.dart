@@ -0,0 +1,87 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'custom_factory.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Order extends _Order {
+  Order({
+    required this.total,
+    required this.discount,
+    required this.payments,
+    required this.fee,
+  });
+
+  factory Order.fromJson(Map<String, dynamic> json) {
+    return Order(
+      total: Money.deserialize(json['total'] as Map<String, dynamic>),
+      discount: json['discount'] == null ? null : Money.deserialize(json['discount'] as Map<String, dynamic>),
+      payments: (json['payments'] as List).map((e) => Money.deserialize(e as Map<String, dynamic>)).toList(),
+      fee: Money.fromJson(json['fee'] as Map<String, dynamic>),
+    );
+  }
+
+  static List<Order> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Order.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Money total;
+
+  @override
+  final Money? discount;
+
+  @override
+  final List<Money> payments;
+
+  @override
+  final Money fee;
+
+  Map<String, dynamic> toJson() => {
+    'total': total.serialize(),
+    'discount': discount?.serialize(),
+    'payments': payments.map((e) => e.serialize()).toList(),
+    'fee': fee.toJson(),
+  };
+
+  Order copyWith({
+    Money? total,
+    Object? discount = _sentinel,
+    List<Money>? payments,
+    Money? fee,
+  }) => Order(
+    total: total ?? this.total,
+    discount: identical(discount, _sentinel) ? this.discount : discount as Money?,
+    payments: payments ?? this.payments,
+    fee: fee ?? this.fee,
+  );
+
+  @override
+  String toString() => 'Order('
+    'total: $total '
+    'discount: $discount '
+    'payments: $payments '
+    'fee: $fee '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Order
+      && other.total == total
+      && other.discount == discount
+      && const DeepCollectionEquality().equals(other.payments, payments)
+      && other.fee == fee;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    total,
+    discount,
+    const DeepCollectionEquality().hash(payments),
+    fee,
+  );
+}
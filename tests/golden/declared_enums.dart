@@ -0,0 +1,20 @@
+part 'declared_enums.flu.dart';
+
+enum Color { red, green, blue }
+
+enum Size {
+  small,
+  large,
+}
+
+// @flu
+abstract class _Palette {
+  Color get primary;
+  Color? get accent;
+  List<Color> get colors;
+  Map<Color, Size> get sizes;
+  // @flu fallback=red
+  Color get fallback;
+  // @flu enum
+  Shade get shade;
+}
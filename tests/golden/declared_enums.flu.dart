@@ -0,0 +1,109 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'declared_enums.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Palette extends _Palette {
+  Palette({
+    required this.primary,
+    required this.accent,
+    required this.colors,
+    required this.sizes,
+    required this.fallback,
+    required this.shade,
+  });
+
+  factory Palette.fromJson(Map<String, dynamic> json) {
+    return Palette(
+      primary: Color.values.singleWhere((v) => v.name == json['primary'] as String),
+      accent: json['accent'] == null ? null : Color.values.singleWhere((v) => v.name == json['accent'] as String),
+      colors: (json['colors'] as List).map((e) => Color.values.singleWhere((v) => v.name == e as String)).toList(),
+      sizes: (json['sizes'] as Map<String, dynamic>).map((k, e) => MapEntry(Color.values.byName(k), Size.values.singleWhere((v) => v.name == e as String))),
+      fallback: Color.values.singleWhere((v) => v.name == json['fallback'] as String, orElse: () => Color.red),
+      shade: Shade.values.singleWhere((v) => v.name == json['shade'] as String),
+    );
+  }
+
+  static List<Palette> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Palette.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Color primary;
+
+  @override
+  final Color? accent;
+
+  @override
+  final List<Color> colors;
+
+  @override
+  final Map<Color, Size> sizes;
+
+  @override
+  final Color fallback;
+
+  @override
+  final Shade shade;
+
+  Map<String, dynamic> toJson() => {
+    'primary': primary.name,
+    'accent': accent?.name,
+    'colors': colors.map((e) => e.name).toList(),
+    'sizes': sizes.map((k, e) => MapEntry(k.name, e.name)),
+    'fallback': fallback.name,
+    'shade': shade.name,
+  };
+
+  Palette copyWith({
+    Color? primary,
+    Object? accent = _sentinel,
+    List<Color>? colors,
+    Map<Color, Size>? sizes,
+    Color? fallback,
+    Shade? shade,
+  }) => Palette(
+    primary: primary ?? this.primary,
+    accent: identical(accent, _sentinel) ? this.accent : accent as Color?,
+    colors: colors ?? this.colors,
+    sizes: sizes ?? this.sizes,
+    fallback: fallback ?? this.fallback,
+    shade: shade ?? this.shade,
+  );
+
+  @override
+  String toString() => 'Palette('
+    'primary: $primary '
+    'accent: $accent '
+    'colors: $colors '
+    'sizes: $sizes '
+    'fallback: $fallback '
+    'shade: $shade '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Palette
+      && other.primary == primary
+      && other.accent == accent
+      && const DeepCollectionEquality().equals(other.colors, colors)
+      && const DeepCollectionEquality().equals(other.sizes, sizes)
+      && other.fallback == fallback
+      && other.shade == shade;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    primary,
+    accent,
+    const DeepCollectionEquality().hash(colors),
+    const DeepCollectionEquality().hash(sizes),
+    fallback,
+    shade,
+  );
+}
@@ -0,0 +1,28 @@
+part 'declared_unserializable_names.flu.dart';
+
+class File {
+  const File(this.name);
+
+  factory File.fromJson(Map<String, dynamic> json) => File(json['name'] as String);
+
+  final String name;
+
+  Map<String, dynamic> toJson() => {'name': name};
+}
+
+// @flu
+abstract class _Timer {
+  const _Timer();
+
+  int get seconds;
+}
+
+// @flu
+abstract class _Upload {
+  const _Upload();
+
+  File get file;
+  List<Timer> get timers;
+  Map<String, File?> get attachments;
+  Completer<int> get pending;
+}
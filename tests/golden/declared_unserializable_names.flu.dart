@@ -0,0 +1,117 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'declared_unserializable_names.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+class Timer extends _Timer {
+  const Timer({
+    required this.seconds,
+  });
+
+  factory Timer.fromJson(Map<String, dynamic> json) {
+    return Timer(
+      seconds: (json['seconds'] as num).toInt(),
+    );
+  }
+
+  static List<Timer> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Timer.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int seconds;
+
+  Map<String, dynamic> toJson() => {
+    'seconds': seconds,
+  };
+
+  Timer copyWith({
+    int? seconds,
+  }) => Timer(
+    seconds: seconds ?? this.seconds,
+  );
+
+  @override
+  String toString() => 'Timer('
+    'seconds: $seconds '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Timer
+      && other.seconds == seconds;
+  }
+
+  @override
+  int get hashCode => seconds.hashCode;
+}
+
+class Upload extends _Upload {
+  const Upload({
+    required this.file,
+    required this.timers,
+    required this.attachments,
+  });
+
+  factory Upload.fromJson(Map<String, dynamic> json) {
+    return Upload(
+      file: File.fromJson(json['file'] as Map<String, dynamic>),
+      timers: (json['timers'] as List).map((e) => Timer.fromJson(e as Map<String, dynamic>)).toList(),
+      attachments: (json['attachments'] as Map<String, dynamic>).map((k, e) => MapEntry(k, e == null ? null : File.fromJson(e as Map<String, dynamic>))),
+    );
+  }
+
+  static List<Upload> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Upload.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final File file;
+
+  @override
+  final List<Timer> timers;
+
+  @override
+  final Map<String, File?> attachments;
+
+  Map<String, dynamic> toJson() => {
+    'file': file.toJson(),
+    'timers': timers.map((e) => e.toJson()).toList(),
+    'attachments': attachments.map((k, e) => MapEntry(k, e?.toJson())),
+  };
+
+  Upload copyWith({
+    File? file,
+    List<Timer>? timers,
+    Map<String, File?>? attachments,
+  }) => Upload(
+    file: file ?? this.file,
+    timers: timers ?? this.timers,
+    attachments: attachments ?? this.attachments,
+  );
+
+  @override
+  String toString() => 'Upload('
+    'file: $file '
+    'timers: $timers '
+    'attachments: $attachments '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Upload
+      && other.file == file
+      && const DeepCollectionEquality().equals(other.timers, timers)
+      && const DeepCollectionEquality().equals(other.attachments, attachments);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    file,
+    const DeepCollectionEquality().hash(timers),
+    const DeepCollectionEquality().hash(attachments),
+  );
+}
@@ -0,0 +1,15 @@
+part 'deprecated.flu.dart';
+
+// @flu
+abstract class _Account {
+  String get id;
+
+  @Deprecated('use id')
+  /// Legacy identifier
+  // @flu key="user_id"
+  String get userId;
+
+  /// Display name
+  @deprecated
+  String? get name;
+}
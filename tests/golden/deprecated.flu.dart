@@ -0,0 +1,78 @@
+// dart format off
+
+// ignore_for_file: deprecated_member_use_from_same_package, avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'deprecated.dart';
+
+const _sentinel = Object();
+
+class Account extends _Account {
+  Account({
+    required this.id,
+    required this.userId,
+    required this.name,
+  });
+
+  factory Account.fromJson(Map<String, dynamic> json) {
+    return Account(
+      id: json['id'] as String,
+      userId: json['user_id'] as String,
+      name: json['name'] as String?,
+    );
+  }
+
+  static List<Account> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Account.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  /// Legacy identifier
+  @Deprecated('use id')
+  @override
+  final String userId;
+
+  /// Display name
+  @deprecated
+  @override
+  final String? name;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+    'user_id': userId,
+    'name': name,
+  };
+
+  Account copyWith({
+    String? id,
+    String? userId,
+    Object? name = _sentinel,
+  }) => Account(
+    id: id ?? this.id,
+    userId: userId ?? this.userId,
+    name: identical(name, _sentinel) ? this.name : name as String?,
+  );
+
+  @override
+  String toString() => 'Account('
+    'id: $id '
+    'userId: $userId '
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Account
+      && other.id == id
+      && other.userId == userId
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    userId,
+    name,
+  );
+}
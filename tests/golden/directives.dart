@@ -0,0 +1,17 @@
+part 'directives.flu.dart';
+
+// @flu
+// @flu: positional, const
+abstract class _Point {
+  const _Point();
+  int get x;
+  int get y;
+  // @flu default=0
+  int get z;
+}
+
+// @flu
+// @flu: mutable
+abstract class _Counter {
+  int get value;
+}
@@ -0,0 +1,111 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'directives.dart';
+
+class Point extends _Point {
+  const Point(this.x, this.y, [this.z = 0]);
+
+  factory Point.fromJson(Map<String, dynamic> json) {
+    return Point(
+      (json['x'] as num).toInt(),
+      (json['y'] as num).toInt(),
+      json['z'] == null ? 0 : (json['z'] as num).toInt(),
+    );
+  }
+
+  static List<Point> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Point.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int x;
+
+  @override
+  final int y;
+
+  @override
+  final int z;
+
+  Map<String, dynamic> toJson() => {
+    'x': x,
+    'y': y,
+    'z': z,
+  };
+
+  Point copyWith({
+    int? x,
+    int? y,
+    int? z,
+  }) => Point(
+    x ?? this.x,
+    y ?? this.y,
+    z ?? this.z,
+  );
+
+  @override
+  String toString() => 'Point('
+    'x: $x '
+    'y: $y '
+    'z: $z '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Point
+      && other.x == x
+      && other.y == y
+      && other.z == z;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    x,
+    y,
+    z,
+  );
+}
+
+class Counter extends _Counter {
+  Counter({
+    required this.value,
+  });
+
+  factory Counter.fromJson(Map<String, dynamic> json) {
+    return Counter(
+      value: (json['value'] as num).toInt(),
+    );
+  }
+
+  static List<Counter> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Counter.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  int value;
+
+  Map<String, dynamic> toJson() => {
+    'value': value,
+  };
+
+  Counter copyWith({
+    int? value,
+  }) => Counter(
+    value: value ?? this.value,
+  );
+
+  @override
+  String toString() => 'Counter('
+    'value: $value '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Counter
+      && other.value == value;
+  }
+
+  @override
+  int get hashCode => value.hashCode;
+}
@@ -0,0 +1,8 @@
+part 'doc_comment_marker.flu.dart';
+
+// @flu
+/// Documented between the marker and the declaration.
+// A regular comment too.
+abstract class _Foo {
+  String get name;
+}
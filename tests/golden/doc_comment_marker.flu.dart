@@ -0,0 +1,48 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'doc_comment_marker.dart';
+
+class Foo extends _Foo {
+  Foo({
+    required this.name,
+  });
+
+  factory Foo.fromJson(Map<String, dynamic> json) {
+    return Foo(
+      name: json['name'] as String,
+    );
+  }
+
+  static List<Foo> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Foo.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+  };
+
+  Foo copyWith({
+    String? name,
+  }) => Foo(
+    name: name ?? this.name,
+  );
+
+  @override
+  String toString() => 'Foo('
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Foo
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => name.hashCode;
+}
@@ -0,0 +1,14 @@
+part 'durations.flu.dart';
+
+// @flu
+abstract class _Timeouts {
+  const _Timeouts();
+
+  Duration get connect;
+  Duration? get idle;
+  // @flu duration=millis
+  Duration get read;
+  // @flu duration=millis
+  Duration? get write;
+  List<Duration> get retries;
+}
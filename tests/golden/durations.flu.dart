@@ -0,0 +1,98 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'durations.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Timeouts extends _Timeouts {
+  const Timeouts({
+    required this.connect,
+    required this.idle,
+    required this.read,
+    required this.write,
+    required this.retries,
+  });
+
+  factory Timeouts.fromJson(Map<String, dynamic> json) {
+    return Timeouts(
+      connect: Duration(microseconds: json['connect'] as int),
+      idle: json['idle'] == null ? null : Duration(microseconds: json['idle'] as int),
+      read: Duration(milliseconds: json['read'] as int),
+      write: json['write'] == null ? null : Duration(milliseconds: json['write'] as int),
+      retries: (json['retries'] as List).map((e) => Duration(microseconds: e as int)).toList(),
+    );
+  }
+
+  static List<Timeouts> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Timeouts.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Duration connect;
+
+  @override
+  final Duration? idle;
+
+  @override
+  final Duration read;
+
+  @override
+  final Duration? write;
+
+  @override
+  final List<Duration> retries;
+
+  Map<String, dynamic> toJson() => {
+    'connect': connect.inMicroseconds,
+    'idle': idle?.inMicroseconds,
+    'read': read.inMilliseconds,
+    'write': write?.inMilliseconds,
+    'retries': retries.map((e) => e.inMicroseconds).toList(),
+  };
+
+  Timeouts copyWith({
+    Duration? connect,
+    Object? idle = _sentinel,
+    Duration? read,
+    Object? write = _sentinel,
+    List<Duration>? retries,
+  }) => Timeouts(
+    connect: connect ?? this.connect,
+    idle: identical(idle, _sentinel) ? this.idle : idle as Duration?,
+    read: read ?? this.read,
+    write: identical(write, _sentinel) ? this.write : write as Duration?,
+    retries: retries ?? this.retries,
+  );
+
+  @override
+  String toString() => 'Timeouts('
+    'connect: $connect '
+    'idle: $idle '
+    'read: $read '
+    'write: $write '
+    'retries: $retries '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Timeouts
+      && other.connect == connect
+      && other.idle == idle
+      && other.read == read
+      && other.write == write
+      && const DeepCollectionEquality().equals(other.retries, retries);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    connect,
+    idle,
+    read,
+    write,
+    const DeepCollectionEquality().hash(retries),
+  );
+}
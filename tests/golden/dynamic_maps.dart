@@ -0,0 +1,7 @@
+part 'dynamic_maps.flu.dart';
+
+// @flu
+abstract class _Event {
+  Map<String, dynamic> get metadata;
+  Map<String, dynamic>? get extra;
+}
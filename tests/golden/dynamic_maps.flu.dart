@@ -0,0 +1,65 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'dynamic_maps.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Event extends _Event {
+  Event({
+    required this.metadata,
+    required this.extra,
+  });
+
+  factory Event.fromJson(Map<String, dynamic> json) {
+    return Event(
+      metadata: json['metadata'] as Map<String, dynamic>,
+      extra: json['extra'] as Map<String, dynamic>?,
+    );
+  }
+
+  static List<Event> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Event.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Map<String, dynamic> metadata;
+
+  @override
+  final Map<String, dynamic>? extra;
+
+  Map<String, dynamic> toJson() => {
+    'metadata': metadata,
+    'extra': extra,
+  };
+
+  Event copyWith({
+    Map<String, dynamic>? metadata,
+    Object? extra = _sentinel,
+  }) => Event(
+    metadata: metadata ?? this.metadata,
+    extra: identical(extra, _sentinel) ? this.extra : extra as Map<String, dynamic>?,
+  );
+
+  @override
+  String toString() => 'Event('
+    'metadata: $metadata '
+    'extra: $extra '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Event
+      && const DeepCollectionEquality().equals(other.metadata, metadata)
+      && const DeepCollectionEquality().equals(other.extra, extra);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(metadata),
+    const DeepCollectionEquality().hash(extra),
+  );
+}
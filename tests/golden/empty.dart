@@ -0,0 +1,4 @@
+part 'empty.flu.dart';
+
+// @flu
+abstract class _Empty {}
@@ -0,0 +1,32 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'empty.dart';
+
+class Empty extends _Empty {
+  Empty();
+
+  factory Empty.fromJson(Map<String, dynamic> json) {
+    return Empty();
+  }
+
+  static List<Empty> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Empty.fromJson(e as Map<String, dynamic>)).toList();
+
+  Map<String, dynamic> toJson() => {};
+
+  Empty copyWith() => Empty();
+
+  @override
+  String toString() => 'Empty()';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Empty;
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
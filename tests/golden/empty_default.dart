@@ -0,0 +1,10 @@
+part 'empty_default.flu.dart';
+
+// @flu
+abstract class _Post {
+  String get title;
+  // @flu emptyDefault
+  List<String>? get tags;
+  // @flu emptyDefault
+  Map<String, int>? get votes;
+}
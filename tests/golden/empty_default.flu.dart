@@ -0,0 +1,74 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'empty_default.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+class Post extends _Post {
+  Post({
+    required this.title,
+    this.tags = const [],
+    this.votes = const {},
+  });
+
+  factory Post.fromJson(Map<String, dynamic> json) {
+    return Post(
+      title: json['title'] as String,
+      tags: json['tags'] == null ? const [] : (json['tags'] as List).map((e) => e as String).toList(),
+      votes: json['votes'] == null ? const {} : (json['votes'] as Map<String, dynamic>).map((k, e) => MapEntry(k, (e as num).toInt())),
+    );
+  }
+
+  static List<Post> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Post.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String title;
+
+  @override
+  final List<String> tags;
+
+  @override
+  final Map<String, int> votes;
+
+  Map<String, dynamic> toJson() => {
+    'title': title,
+    'tags': tags,
+    'votes': votes,
+  };
+
+  Post copyWith({
+    String? title,
+    List<String>? tags,
+    Map<String, int>? votes,
+  }) => Post(
+    title: title ?? this.title,
+    tags: tags ?? this.tags,
+    votes: votes ?? this.votes,
+  );
+
+  @override
+  String toString() => 'Post('
+    'title: $title '
+    'tags: $tags '
+    'votes: $votes '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Post
+      && other.title == title
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && const DeepCollectionEquality().equals(other.votes, votes);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    title,
+    const DeepCollectionEquality().hash(tags),
+    const DeepCollectionEquality().hash(votes),
+  );
+}
@@ -0,0 +1,14 @@
+part 'empty_if_null.flu.dart';
+
+// @flu
+abstract class _Post {
+  const _Post();
+
+  // @flu emptyIfNull
+  List<String>? get tags;
+  // @flu emptyIfNull
+  Set<int>? get likes;
+  // @flu emptyIfNull
+  Map<String, int>? get reactions;
+  List<String>? get notes;
+}
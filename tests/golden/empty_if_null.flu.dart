@@ -0,0 +1,87 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'empty_if_null.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Post extends _Post {
+  const Post({
+    required this.tags,
+    required this.likes,
+    required this.reactions,
+    required this.notes,
+  });
+
+  factory Post.fromJson(Map<String, dynamic> json) {
+    return Post(
+      tags: (json['tags'] as List?)?.map((e) => e as String).toList() ?? const [],
+      likes: (json['likes'] as List?)?.map((e) => (e as num).toInt()).toSet() ?? const <int>{},
+      reactions: (json['reactions'] as Map<String, dynamic>?)?.map((k, e) => MapEntry(k, (e as num).toInt())) ?? const {},
+      notes: (json['notes'] as List?)?.map((e) => e as String).toList(),
+    );
+  }
+
+  static List<Post> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Post.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final List<String>? tags;
+
+  @override
+  final Set<int>? likes;
+
+  @override
+  final Map<String, int>? reactions;
+
+  @override
+  final List<String>? notes;
+
+  Map<String, dynamic> toJson() => {
+    'tags': tags,
+    'likes': likes?.toList(),
+    'reactions': reactions,
+    'notes': notes,
+  };
+
+  Post copyWith({
+    Object? tags = _sentinel,
+    Object? likes = _sentinel,
+    Object? reactions = _sentinel,
+    Object? notes = _sentinel,
+  }) => Post(
+    tags: identical(tags, _sentinel) ? this.tags : tags as List<String>?,
+    likes: identical(likes, _sentinel) ? this.likes : likes as Set<int>?,
+    reactions: identical(reactions, _sentinel) ? this.reactions : reactions as Map<String, int>?,
+    notes: identical(notes, _sentinel) ? this.notes : notes as List<String>?,
+  );
+
+  @override
+  String toString() => 'Post('
+    'tags: $tags '
+    'likes: $likes '
+    'reactions: $reactions '
+    'notes: $notes '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Post
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && const DeepCollectionEquality().equals(other.likes, likes)
+      && const DeepCollectionEquality().equals(other.reactions, reactions)
+      && const DeepCollectionEquality().equals(other.notes, notes);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(tags),
+    const DeepCollectionEquality().hash(likes),
+    const DeepCollectionEquality().hash(reactions),
+    const DeepCollectionEquality().hash(notes),
+  );
+}
@@ -0,0 +1,17 @@
+part 'enum_lists.flu.dart';
+
+enum Status { active, inactive, unknown }
+
+// @flu
+abstract class _Statuses {
+  // @flu enum fallback=unknown
+  List<Status?> get history;
+  // @flu enum fallback=unknown
+  List<Status?>? get pending;
+  // @flu enum
+  List<Status> get required;
+  // @flu enum values="active:A,inactive:I"
+  List<Status?> get coded;
+  // @flu enum values="active:A,inactive:I"
+  Status? get current;
+}
@@ -0,0 +1,98 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'enum_lists.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Statuses extends _Statuses {
+  Statuses({
+    required this.history,
+    required this.pending,
+    required this.required,
+    required this.coded,
+    required this.current,
+  });
+
+  factory Statuses.fromJson(Map<String, dynamic> json) {
+    return Statuses(
+      history: (json['history'] as List).map((e) => e == null ? null : Status.values.singleWhere((v) => v.name == e as String, orElse: () => Status.unknown)).toList(),
+      pending: (json['pending'] as List?)?.map((e) => e == null ? null : Status.values.singleWhere((v) => v.name == e as String, orElse: () => Status.unknown)).toList(),
+      required: (json['required'] as List).map((e) => Status.values.singleWhere((v) => v.name == e as String)).toList(),
+      coded: (json['coded'] as List).map((e) => e == null ? null : Status.values.singleWhere((v) => (const {Status.active: 'A', Status.inactive: 'I'}[v] ?? v.name) == e as String)).toList(),
+      current: json['current'] == null ? null : Status.values.singleWhere((v) => (const {Status.active: 'A', Status.inactive: 'I'}[v] ?? v.name) == json['current'] as String),
+    );
+  }
+
+  static List<Statuses> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Statuses.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final List<Status?> history;
+
+  @override
+  final List<Status?>? pending;
+
+  @override
+  final List<Status> required;
+
+  @override
+  final List<Status?> coded;
+
+  @override
+  final Status? current;
+
+  Map<String, dynamic> toJson() => {
+    'history': history.map((e) => e?.name).toList(),
+    'pending': pending?.map((e) => e?.name).toList(),
+    'required': required.map((e) => e.name).toList(),
+    'coded': coded.map((e) => e == null ? null : const {Status.active: 'A', Status.inactive: 'I'}[e] ?? e.name).toList(),
+    'current': current == null ? null : const {Status.active: 'A', Status.inactive: 'I'}[current!] ?? current!.name,
+  };
+
+  Statuses copyWith({
+    List<Status?>? history,
+    Object? pending = _sentinel,
+    List<Status>? required,
+    List<Status?>? coded,
+    Object? current = _sentinel,
+  }) => Statuses(
+    history: history ?? this.history,
+    pending: identical(pending, _sentinel) ? this.pending : pending as List<Status?>?,
+    required: required ?? this.required,
+    coded: coded ?? this.coded,
+    current: identical(current, _sentinel) ? this.current : current as Status?,
+  );
+
+  @override
+  String toString() => 'Statuses('
+    'history: $history '
+    'pending: $pending '
+    'required: $required '
+    'coded: $coded '
+    'current: $current '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Statuses
+      && const DeepCollectionEquality().equals(other.history, history)
+      && const DeepCollectionEquality().equals(other.pending, pending)
+      && const DeepCollectionEquality().equals(other.required, required)
+      && const DeepCollectionEquality().equals(other.coded, coded)
+      && other.current == current;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(history),
+    const DeepCollectionEquality().hash(pending),
+    const DeepCollectionEquality().hash(required),
+    const DeepCollectionEquality().hash(coded),
+    current,
+  );
+}
@@ -0,0 +1,14 @@
+part 'equals_ignore.flu.dart';
+
+// @flu
+abstract class _Cached {
+  String get id;
+  // @flu equalsIgnore
+  DateTime get fetchedAt;
+}
+
+// @flu
+abstract class _Stamp {
+  // @flu equalsIgnore
+  DateTime get at;
+}
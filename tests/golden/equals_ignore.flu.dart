@@ -0,0 +1,99 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'equals_ignore.dart';
+
+class Cached extends _Cached {
+  Cached({
+    required this.id,
+    required this.fetchedAt,
+  });
+
+  factory Cached.fromJson(Map<String, dynamic> json) {
+    return Cached(
+      id: json['id'] as String,
+      fetchedAt: DateTime.parse(json['fetchedAt'] as String),
+    );
+  }
+
+  static List<Cached> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Cached.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  @override
+  final DateTime fetchedAt;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+    'fetchedAt': fetchedAt.toIso8601String(),
+  };
+
+  Cached copyWith({
+    String? id,
+    DateTime? fetchedAt,
+  }) => Cached(
+    id: id ?? this.id,
+    fetchedAt: fetchedAt ?? this.fetchedAt,
+  );
+
+  @override
+  String toString() => 'Cached('
+    'id: $id '
+    'fetchedAt: $fetchedAt '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Cached
+      && other.id == id;
+  }
+
+  @override
+  int get hashCode => id.hashCode;
+}
+
+class Stamp extends _Stamp {
+  Stamp({
+    required this.at,
+  });
+
+  factory Stamp.fromJson(Map<String, dynamic> json) {
+    return Stamp(
+      at: DateTime.parse(json['at'] as String),
+    );
+  }
+
+  static List<Stamp> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Stamp.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final DateTime at;
+
+  Map<String, dynamic> toJson() => {
+    'at': at.toIso8601String(),
+  };
+
+  Stamp copyWith({
+    DateTime? at,
+  }) => Stamp(
+    at: at ?? this.at,
+  );
+
+  @override
+  String toString() => 'Stamp('
+    'at: $at '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Stamp;
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
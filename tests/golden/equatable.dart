@@ -0,0 +1,16 @@
+// args: --equatable
+part 'equatable.flu.dart';
+
+// @flu
+abstract class _Point {
+  const _Point();
+
+  int get x;
+  int get y;
+  List<String> get labels;
+}
+
+// @flu
+abstract class _Empty {
+  const _Empty();
+}
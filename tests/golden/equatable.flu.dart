@@ -0,0 +1,82 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'equatable.dart';
+
+// requires `import 'package:equatable/equatable.dart';` in the library
+
+class Point extends _Point with EquatableMixin {
+  const Point({
+    required this.x,
+    required this.y,
+    required this.labels,
+  });
+
+  factory Point.fromJson(Map<String, dynamic> json) {
+    return Point(
+      x: (json['x'] as num).toInt(),
+      y: (json['y'] as num).toInt(),
+      labels: (json['labels'] as List).map((e) => e as String).toList(),
+    );
+  }
+
+  static List<Point> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Point.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int x;
+
+  @override
+  final int y;
+
+  @override
+  final List<String> labels;
+
+  Map<String, dynamic> toJson() => {
+    'x': x,
+    'y': y,
+    'labels': labels,
+  };
+
+  Point copyWith({
+    int? x,
+    int? y,
+    List<String>? labels,
+  }) => Point(
+    x: x ?? this.x,
+    y: y ?? this.y,
+    labels: labels ?? this.labels,
+  );
+
+  @override
+  String toString() => 'Point('
+    'x: $x '
+    'y: $y '
+    'labels: $labels '
+    ')';
+
+  @override
+  List<Object?> get props => [x, y, labels];
+}
+
+class Empty extends _Empty with EquatableMixin {
+  const Empty();
+
+  factory Empty.fromJson(Map<String, dynamic> json) {
+    return Empty();
+  }
+
+  static List<Empty> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Empty.fromJson(e as Map<String, dynamic>)).toList();
+
+  Map<String, dynamic> toJson() => {};
+
+  Empty copyWith() => Empty();
+
+  @override
+  String toString() => 'Empty()';
+
+  @override
+  List<Object?> get props => [];
+}
@@ -0,0 +1,19 @@
+part 'errors.flu.dart';
+
+enum Status { active, inactive }
+
+// @flu
+abstract class _Invalid {
+  const _Invalid();
+
+  String get class;
+  List<int get broken;
+  Map<bool, String> get flags;
+  int get id;
+  String get id;
+  // @flu enum index fallback=active
+  Status get status;
+}
+
+// @flu
+class NotAbstract {}
@@ -0,0 +1,61 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'errors.dart';
+
+class Invalid extends _Invalid {
+  const Invalid({
+    required this.id,
+    required this.status,
+  });
+
+  factory Invalid.fromJson(Map<String, dynamic> json) {
+    return Invalid(
+      id: (json['id'] as num).toInt(),
+      status: Status.values[json['status'] as int],
+    );
+  }
+
+  static List<Invalid> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Invalid.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int id;
+
+  @override
+  final Status status;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+    'status': status.index,
+  };
+
+  Invalid copyWith({
+    int? id,
+    Status? status,
+  }) => Invalid(
+    id: id ?? this.id,
+    status: status ?? this.status,
+  );
+
+  @override
+  String toString() => 'Invalid('
+    'id: $id '
+    'status: $status '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Invalid
+      && other.id == id
+      && other.status == status;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    status,
+  );
+}
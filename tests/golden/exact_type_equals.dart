@@ -0,0 +1,16 @@
+// args: --exact-type-equals
+part 'exact_type_equals.flu.dart';
+
+// @flu
+abstract class _Animal {
+  const _Animal();
+
+  String get name;
+}
+
+// @flu
+abstract class _Dog extends _Animal {
+  const _Dog();
+
+  bool get goodBoy;
+}
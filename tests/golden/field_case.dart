@@ -0,0 +1,17 @@
+// args: --key-case snake
+part 'field_case.flu.dart';
+
+// @flu
+abstract class _Order {
+  const _Order();
+
+  int get orderId;
+  // @flu case=camel
+  String get customerName;
+  // @flu case=kebab
+  String get shippingMethod;
+  // @flu case=pascal
+  double get totalPrice;
+  // @flu case=kebab key="created"
+  DateTime get createdAt;
+}
@@ -0,0 +1,94 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'field_case.dart';
+
+class Order extends _Order {
+  const Order({
+    required this.orderId,
+    required this.customerName,
+    required this.shippingMethod,
+    required this.totalPrice,
+    required this.createdAt,
+  });
+
+  factory Order.fromJson(Map<String, dynamic> json) {
+    return Order(
+      orderId: (json['order_id'] as num).toInt(),
+      customerName: json['customerName'] as String,
+      shippingMethod: json['shipping-method'] as String,
+      totalPrice: (json['TotalPrice'] as num).toDouble(),
+      createdAt: DateTime.parse(json['created'] as String),
+    );
+  }
+
+  static List<Order> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Order.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int orderId;
+
+  @override
+  final String customerName;
+
+  @override
+  final String shippingMethod;
+
+  @override
+  final double totalPrice;
+
+  @override
+  final DateTime createdAt;
+
+  Map<String, dynamic> toJson() => {
+    'order_id': orderId,
+    'customerName': customerName,
+    'shipping-method': shippingMethod,
+    'TotalPrice': totalPrice,
+    'created': createdAt.toIso8601String(),
+  };
+
+  Order copyWith({
+    int? orderId,
+    String? customerName,
+    String? shippingMethod,
+    double? totalPrice,
+    DateTime? createdAt,
+  }) => Order(
+    orderId: orderId ?? this.orderId,
+    customerName: customerName ?? this.customerName,
+    shippingMethod: shippingMethod ?? this.shippingMethod,
+    totalPrice: totalPrice ?? this.totalPrice,
+    createdAt: createdAt ?? this.createdAt,
+  );
+
+  @override
+  String toString() => 'Order('
+    'orderId: $orderId '
+    'customerName: $customerName '
+    'shippingMethod: $shippingMethod '
+    'totalPrice: $totalPrice '
+    'createdAt: $createdAt '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Order
+      && other.orderId == orderId
+      && other.customerName == customerName
+      && other.shippingMethod == shippingMethod
+      && other.totalPrice == totalPrice
+      && other.createdAt == createdAt;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    orderId,
+    customerName,
+    shippingMethod,
+    totalPrice,
+    createdAt,
+  );
+}
@@ -0,0 +1,20 @@
+part 'field_options.flu.dart';
+
+enum Status { active, inactive }
+
+// @flu
+abstract class _Options {
+  // @flu key="the_count"
+  // @flu default=3
+  int get count;
+  // @flu equalsIgnore
+  DateTime get fetchedAt;
+  // @flu emptyDefault
+  List<String>? get tags;
+  // @flu lenient
+  Address get address;
+  // @flu enum values="active:A,inactive:I" fallback=active
+  Status get status;
+  // @flu omitIfNull
+  String? get note;
+}
@@ -0,0 +1,107 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'field_options.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Options extends _Options {
+  Options({
+    this.count = 3,
+    required this.fetchedAt,
+    this.tags = const [],
+    required this.address,
+    required this.status,
+    required this.note,
+  });
+
+  factory Options.fromJson(Map<String, dynamic> json) {
+    return Options(
+      count: json['the_count'] == null ? 3 : (json['the_count'] as num).toInt(),
+      fetchedAt: DateTime.parse(json['fetchedAt'] as String),
+      tags: json['tags'] == null ? const [] : (json['tags'] as List).map((e) => e as String).toList(),
+      address: json['address'] is Address ? json['address'] as Address : Address.fromJson(json['address'] as Map<String, dynamic>),
+      status: Status.values.singleWhere((v) => (const {Status.active: 'A', Status.inactive: 'I'}[v] ?? v.name) == json['status'] as String, orElse: () => Status.active),
+      note: json['note'] as String?,
+    );
+  }
+
+  static List<Options> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Options.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int count;
+
+  @override
+  final DateTime fetchedAt;
+
+  @override
+  final List<String> tags;
+
+  @override
+  final Address address;
+
+  @override
+  final Status status;
+
+  @override
+  final String? note;
+
+  Map<String, dynamic> toJson() => {
+    'the_count': count,
+    'fetchedAt': fetchedAt.toIso8601String(),
+    'tags': tags,
+    'address': address.toJson(),
+    'status': const {Status.active: 'A', Status.inactive: 'I'}[status] ?? status.name,
+    if (note != null) 'note': note,
+  };
+
+  Options copyWith({
+    int? count,
+    DateTime? fetchedAt,
+    List<String>? tags,
+    Address? address,
+    Status? status,
+    Object? note = _sentinel,
+  }) => Options(
+    count: count ?? this.count,
+    fetchedAt: fetchedAt ?? this.fetchedAt,
+    tags: tags ?? this.tags,
+    address: address ?? this.address,
+    status: status ?? this.status,
+    note: identical(note, _sentinel) ? this.note : note as String?,
+  );
+
+  @override
+  String toString() => 'Options('
+    'count: $count '
+    'fetchedAt: $fetchedAt '
+    'tags: $tags '
+    'address: $address '
+    'status: $status '
+    'note: $note '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Options
+      && other.count == count
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && other.address == address
+      && other.status == status
+      && other.note == note;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    count,
+    const DeepCollectionEquality().hash(tags),
+    address,
+    status,
+    note,
+  );
+}
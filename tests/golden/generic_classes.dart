@@ -0,0 +1,18 @@
+// args: --merge --copy-with-map
+part 'generic_classes.flu.dart';
+
+// @flu
+// @flu: rawJson, mapAliases
+abstract class _Box<T> {
+  T get value;
+  T? get previous;
+  List<T> get items;
+  Map<String, T> get byName;
+  String get label;
+}
+
+// @flu
+abstract class _Pair<K extends Object, V> {
+  K get first;
+  V get second;
+}
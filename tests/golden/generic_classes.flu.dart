@@ -0,0 +1,194 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'generic_classes.dart';
+
+// requires `import 'dart:convert';` in the library
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Box<T> extends _Box<T> {
+  Box({
+    required this.value,
+    required this.previous,
+    required this.items,
+    required this.byName,
+    required this.label,
+  });
+
+  factory Box.fromJson(Map<String, dynamic> json, T Function(Object?) fromJsonT) {
+    return Box(
+      value: fromJsonT(json['value']),
+      previous: json['previous'] == null ? null : fromJsonT(json['previous']),
+      items: (json['items'] as List).map((e) => fromJsonT(e)).toList(),
+      byName: (json['byName'] as Map<String, dynamic>).map((k, e) => MapEntry(k, fromJsonT(e))),
+      label: json['label'] as String,
+    );
+  }
+
+  static List<Box<T>> fromJsonList<T>(List<dynamic> json, T Function(Object?) fromJsonT) =>
+      json.map((e) => Box<T>.fromJson(e as Map<String, dynamic>, fromJsonT)).toList();
+
+  factory Box.fromMap(Map<String, dynamic> map, T Function(Object?) fromJsonT) => Box.fromJson(map, fromJsonT);
+
+  factory Box.fromRawJson(String str, T Function(Object?) fromJsonT) =>
+      Box.fromJson(json.decode(str) as Map<String, dynamic>, fromJsonT);
+
+  static List<Box<T>> fromRawJsonList<T>(String str, T Function(Object?) fromJsonT) =>
+      fromJsonList(json.decode(str) as List<dynamic>, fromJsonT);
+
+  @override
+  final T value;
+
+  @override
+  final T? previous;
+
+  @override
+  final List<T> items;
+
+  @override
+  final Map<String, T> byName;
+
+  @override
+  final String label;
+
+  Map<String, dynamic> toJson(Object? Function(T) toJsonT) => {
+    'value': toJsonT(value),
+    'previous': previous == null ? null : toJsonT(previous!),
+    'items': items.map((e) => toJsonT(e)).toList(),
+    'byName': byName.map((k, e) => MapEntry(k, toJsonT(e))),
+    'label': label,
+  };
+
+  Map<String, dynamic> toMap(Object? Function(T) toJsonT) => toJson(toJsonT);
+
+  String toRawJson(Object? Function(T) toJsonT) => json.encode(toJson(toJsonT));
+
+  Box<T> copyWith({
+    T? value,
+    Object? previous = _sentinel,
+    List<T>? items,
+    Map<String, T>? byName,
+    String? label,
+  }) => Box(
+    value: value ?? this.value,
+    previous: identical(previous, _sentinel) ? this.previous : previous as T?,
+    items: items ?? this.items,
+    byName: byName ?? this.byName,
+    label: label ?? this.label,
+  );
+
+  Box<T> merge(Box<T> other) => Box(
+    value: other.value,
+    previous: other.previous ?? this.previous,
+    items: other.items,
+    byName: other.byName,
+    label: other.label,
+  );
+
+  Box<T> copyWithMap(Map<String, dynamic> json, T Function(Object?) fromJsonT) => Box(
+    value: json.containsKey('value') ? fromJsonT(json['value']) : this.value,
+    previous: json.containsKey('previous') ? (json['previous'] == null ? null : fromJsonT(json['previous'])) : this.previous,
+    items: json.containsKey('items') ? (json['items'] as List).map((e) => fromJsonT(e)).toList() : this.items,
+    byName: json.containsKey('byName') ? (json['byName'] as Map<String, dynamic>).map((k, e) => MapEntry(k, fromJsonT(e))) : this.byName,
+    label: json.containsKey('label') ? json['label'] as String : this.label,
+  );
+
+  @override
+  String toString() => 'Box('
+    'value: $value '
+    'previous: $previous '
+    'items: $items '
+    'byName: $byName '
+    'label: $label '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Box<T>
+      && other.value == value
+      && other.previous == previous
+      && const DeepCollectionEquality().equals(other.items, items)
+      && const DeepCollectionEquality().equals(other.byName, byName)
+      && other.label == label;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    value,
+    previous,
+    const DeepCollectionEquality().hash(items),
+    const DeepCollectionEquality().hash(byName),
+    label,
+  );
+}
+
+class Pair<K extends Object, V> extends _Pair<K, V> {
+  Pair({
+    required this.first,
+    required this.second,
+  });
+
+  factory Pair.fromJson(Map<String, dynamic> json, K Function(Object?) fromJsonK, V Function(Object?) fromJsonV) {
+    return Pair(
+      first: fromJsonK(json['first']),
+      second: fromJsonV(json['second']),
+    );
+  }
+
+  static List<Pair<K, V>> fromJsonList<K extends Object, V>(List<dynamic> json, K Function(Object?) fromJsonK, V Function(Object?) fromJsonV) =>
+      json.map((e) => Pair<K, V>.fromJson(e as Map<String, dynamic>, fromJsonK, fromJsonV)).toList();
+
+  @override
+  final K first;
+
+  @override
+  final V second;
+
+  Map<String, dynamic> toJson(Object? Function(K) toJsonK, Object? Function(V) toJsonV) => {
+    'first': toJsonK(first),
+    'second': toJsonV(second),
+  };
+
+  Pair<K, V> copyWith({
+    K? first,
+    V? second,
+  }) => Pair(
+    first: first ?? this.first,
+    second: second ?? this.second,
+  );
+
+  Pair<K, V> merge(Pair<K, V> other) => Pair(
+    first: other.first,
+    second: other.second,
+  );
+
+  Pair<K, V> copyWithMap(Map<String, dynamic> json, K Function(Object?) fromJsonK, V Function(Object?) fromJsonV) => Pair(
+    first: json.containsKey('first') ? fromJsonK(json['first']) : this.first,
+    second: json.containsKey('second') ? fromJsonV(json['second']) : this.second,
+  );
+
+  @override
+  String toString() => 'Pair('
+    'first: $first '
+    'second: $second '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Pair<K, V>
+      && other.first == first
+      && other.second == second;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    first,
+    second,
+  );
+}
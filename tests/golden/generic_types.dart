@@ -0,0 +1,13 @@
+part 'generic_types.flu.dart';
+
+// @flu
+abstract class _Generics {
+  List<Map<String, int>> get counts;
+  List< List<int> > get grid;
+  Map<String, List<Map<String, int>>> get nested;
+  Map<String, int,> get trailing;
+  Set<List<String>>? get tags;
+  List< int > get spaced;
+  List<int > get spacedEnd;
+  List< int> get spacedStart;
+}
@@ -0,0 +1,131 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'generic_types.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Generics extends _Generics {
+  Generics({
+    required this.counts,
+    required this.grid,
+    required this.nested,
+    required this.trailing,
+    required this.tags,
+    required this.spaced,
+    required this.spacedEnd,
+    required this.spacedStart,
+  });
+
+  factory Generics.fromJson(Map<String, dynamic> json) {
+    return Generics(
+      counts: (json['counts'] as List).map((e) => (e as Map<String, dynamic>).map((k, e) => MapEntry(k, (e as num).toInt()))).toList(),
+      grid: (json['grid'] as List).map((e) => (e as List).map((e) => (e as num).toInt()).toList()).toList(),
+      nested: (json['nested'] as Map<String, dynamic>).map((k, e) => MapEntry(k, (e as List).map((e) => (e as Map<String, dynamic>).map((k, e) => MapEntry(k, (e as num).toInt()))).toList())),
+      trailing: (json['trailing'] as Map<String, dynamic>).map((k, e) => MapEntry(k, (e as num).toInt())),
+      tags: (json['tags'] as List?)?.map((e) => (e as List).map((e) => e as String).toList()).toSet(),
+      spaced: (json['spaced'] as List).map((e) => (e as num).toInt()).toList(),
+      spacedEnd: (json['spacedEnd'] as List).map((e) => (e as num).toInt()).toList(),
+      spacedStart: (json['spacedStart'] as List).map((e) => (e as num).toInt()).toList(),
+    );
+  }
+
+  static List<Generics> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Generics.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final List<Map<String, int>> counts;
+
+  @override
+  final List<List<int>> grid;
+
+  @override
+  final Map<String, List<Map<String, int>>> nested;
+
+  @override
+  final Map<String, int> trailing;
+
+  @override
+  final Set<List<String>>? tags;
+
+  @override
+  final List<int> spaced;
+
+  @override
+  final List<int> spacedEnd;
+
+  @override
+  final List<int> spacedStart;
+
+  Map<String, dynamic> toJson() => {
+    'counts': counts,
+    'grid': grid,
+    'nested': nested,
+    'trailing': trailing,
+    'tags': tags?.toList(),
+    'spaced': spaced,
+    'spacedEnd': spacedEnd,
+    'spacedStart': spacedStart,
+  };
+
+  Generics copyWith({
+    List<Map<String, int>>? counts,
+    List<List<int>>? grid,
+    Map<String, List<Map<String, int>>>? nested,
+    Map<String, int>? trailing,
+    Object? tags = _sentinel,
+    List<int>? spaced,
+    List<int>? spacedEnd,
+    List<int>? spacedStart,
+  }) => Generics(
+    counts: counts ?? this.counts,
+    grid: grid ?? this.grid,
+    nested: nested ?? this.nested,
+    trailing: trailing ?? this.trailing,
+    tags: identical(tags, _sentinel) ? this.tags : tags as Set<List<String>>?,
+    spaced: spaced ?? this.spaced,
+    spacedEnd: spacedEnd ?? this.spacedEnd,
+    spacedStart: spacedStart ?? this.spacedStart,
+  );
+
+  @override
+  String toString() => 'Generics('
+    'counts: $counts '
+    'grid: $grid '
+    'nested: $nested '
+    'trailing: $trailing '
+    'tags: $tags '
+    'spaced: $spaced '
+    'spacedEnd: $spacedEnd '
+    'spacedStart: $spacedStart '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Generics
+      && const DeepCollectionEquality().equals(other.counts, counts)
+      && const DeepCollectionEquality().equals(other.grid, grid)
+      && const DeepCollectionEquality().equals(other.nested, nested)
+      && const DeepCollectionEquality().equals(other.trailing, trailing)
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && const DeepCollectionEquality().equals(other.spaced, spaced)
+      && const DeepCollectionEquality().equals(other.spacedEnd, spacedEnd)
+      && const DeepCollectionEquality().equals(other.spacedStart, spacedStart);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(counts),
+    const DeepCollectionEquality().hash(grid),
+    const DeepCollectionEquality().hash(nested),
+    const DeepCollectionEquality().hash(trailing),
+    const DeepCollectionEquality().hash(tags),
+    const DeepCollectionEquality().hash(spaced),
+    const DeepCollectionEquality().hash(spacedEnd),
+    const DeepCollectionEquality().hash(spacedStart),
+  );
+}
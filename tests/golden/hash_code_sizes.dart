@@ -0,0 +1,54 @@
+part 'hash_code_sizes.flu.dart';
+
+// @flu
+abstract class _Twenty {
+  const _Twenty();
+
+  int get f1;
+  int get f2;
+  int get f3;
+  int get f4;
+  int get f5;
+  int get f6;
+  int get f7;
+  int get f8;
+  int get f9;
+  int get f10;
+  int get f11;
+  int get f12;
+  int get f13;
+  int get f14;
+  int get f15;
+  int get f16;
+  int get f17;
+  int get f18;
+  int get f19;
+  int get f20;
+}
+
+// @flu
+abstract class _TwentyOne {
+  const _TwentyOne();
+
+  int get f1;
+  int get f2;
+  int get f3;
+  int get f4;
+  int get f5;
+  int get f6;
+  int get f7;
+  int get f8;
+  int get f9;
+  int get f10;
+  int get f11;
+  int get f12;
+  int get f13;
+  int get f14;
+  int get f15;
+  int get f16;
+  int get f17;
+  int get f18;
+  int get f19;
+  int get f20;
+  int get f21;
+}
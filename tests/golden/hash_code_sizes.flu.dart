@@ -0,0 +1,524 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'hash_code_sizes.dart';
+
+class Twenty extends _Twenty {
+  const Twenty({
+    required this.f1,
+    required this.f2,
+    required this.f3,
+    required this.f4,
+    required this.f5,
+    required this.f6,
+    required this.f7,
+    required this.f8,
+    required this.f9,
+    required this.f10,
+    required this.f11,
+    required this.f12,
+    required this.f13,
+    required this.f14,
+    required this.f15,
+    required this.f16,
+    required this.f17,
+    required this.f18,
+    required this.f19,
+    required this.f20,
+  });
+
+  factory Twenty.fromJson(Map<String, dynamic> json) {
+    return Twenty(
+      f1: (json['f1'] as num).toInt(),
+      f2: (json['f2'] as num).toInt(),
+      f3: (json['f3'] as num).toInt(),
+      f4: (json['f4'] as num).toInt(),
+      f5: (json['f5'] as num).toInt(),
+      f6: (json['f6'] as num).toInt(),
+      f7: (json['f7'] as num).toInt(),
+      f8: (json['f8'] as num).toInt(),
+      f9: (json['f9'] as num).toInt(),
+      f10: (json['f10'] as num).toInt(),
+      f11: (json['f11'] as num).toInt(),
+      f12: (json['f12'] as num).toInt(),
+      f13: (json['f13'] as num).toInt(),
+      f14: (json['f14'] as num).toInt(),
+      f15: (json['f15'] as num).toInt(),
+      f16: (json['f16'] as num).toInt(),
+      f17: (json['f17'] as num).toInt(),
+      f18: (json['f18'] as num).toInt(),
+      f19: (json['f19'] as num).toInt(),
+      f20: (json['f20'] as num).toInt(),
+    );
+  }
+
+  static List<Twenty> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Twenty.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int f1;
+
+  @override
+  final int f2;
+
+  @override
+  final int f3;
+
+  @override
+  final int f4;
+
+  @override
+  final int f5;
+
+  @override
+  final int f6;
+
+  @override
+  final int f7;
+
+  @override
+  final int f8;
+
+  @override
+  final int f9;
+
+  @override
+  final int f10;
+
+  @override
+  final int f11;
+
+  @override
+  final int f12;
+
+  @override
+  final int f13;
+
+  @override
+  final int f14;
+
+  @override
+  final int f15;
+
+  @override
+  final int f16;
+
+  @override
+  final int f17;
+
+  @override
+  final int f18;
+
+  @override
+  final int f19;
+
+  @override
+  final int f20;
+
+  Map<String, dynamic> toJson() => {
+    'f1': f1,
+    'f2': f2,
+    'f3': f3,
+    'f4': f4,
+    'f5': f5,
+    'f6': f6,
+    'f7': f7,
+    'f8': f8,
+    'f9': f9,
+    'f10': f10,
+    'f11': f11,
+    'f12': f12,
+    'f13': f13,
+    'f14': f14,
+    'f15': f15,
+    'f16': f16,
+    'f17': f17,
+    'f18': f18,
+    'f19': f19,
+    'f20': f20,
+  };
+
+  Twenty copyWith({
+    int? f1,
+    int? f2,
+    int? f3,
+    int? f4,
+    int? f5,
+    int? f6,
+    int? f7,
+    int? f8,
+    int? f9,
+    int? f10,
+    int? f11,
+    int? f12,
+    int? f13,
+    int? f14,
+    int? f15,
+    int? f16,
+    int? f17,
+    int? f18,
+    int? f19,
+    int? f20,
+  }) => Twenty(
+    f1: f1 ?? this.f1,
+    f2: f2 ?? this.f2,
+    f3: f3 ?? this.f3,
+    f4: f4 ?? this.f4,
+    f5: f5 ?? this.f5,
+    f6: f6 ?? this.f6,
+    f7: f7 ?? this.f7,
+    f8: f8 ?? this.f8,
+    f9: f9 ?? this.f9,
+    f10: f10 ?? this.f10,
+    f11: f11 ?? this.f11,
+    f12: f12 ?? this.f12,
+    f13: f13 ?? this.f13,
+    f14: f14 ?? this.f14,
+    f15: f15 ?? this.f15,
+    f16: f16 ?? this.f16,
+    f17: f17 ?? this.f17,
+    f18: f18 ?? this.f18,
+    f19: f19 ?? this.f19,
+    f20: f20 ?? this.f20,
+  );
+
+  @override
+  String toString() => 'Twenty('
+    'f1: $f1 '
+    'f2: $f2 '
+    'f3: $f3 '
+    'f4: $f4 '
+    'f5: $f5 '
+    'f6: $f6 '
+    'f7: $f7 '
+    'f8: $f8 '
+    'f9: $f9 '
+    'f10: $f10 '
+    'f11: $f11 '
+    'f12: $f12 '
+    'f13: $f13 '
+    'f14: $f14 '
+    'f15: $f15 '
+    'f16: $f16 '
+    'f17: $f17 '
+    'f18: $f18 '
+    'f19: $f19 '
+    'f20: $f20 '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Twenty
+      && other.f1 == f1
+      && other.f2 == f2
+      && other.f3 == f3
+      && other.f4 == f4
+      && other.f5 == f5
+      && other.f6 == f6
+      && other.f7 == f7
+      && other.f8 == f8
+      && other.f9 == f9
+      && other.f10 == f10
+      && other.f11 == f11
+      && other.f12 == f12
+      && other.f13 == f13
+      && other.f14 == f14
+      && other.f15 == f15
+      && other.f16 == f16
+      && other.f17 == f17
+      && other.f18 == f18
+      && other.f19 == f19
+      && other.f20 == f20;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    f1,
+    f2,
+    f3,
+    f4,
+    f5,
+    f6,
+    f7,
+    f8,
+    f9,
+    f10,
+    f11,
+    f12,
+    f13,
+    f14,
+    f15,
+    f16,
+    f17,
+    f18,
+    f19,
+    f20,
+  );
+}
+
+class TwentyOne extends _TwentyOne {
+  const TwentyOne({
+    required this.f1,
+    required this.f2,
+    required this.f3,
+    required this.f4,
+    required this.f5,
+    required this.f6,
+    required this.f7,
+    required this.f8,
+    required this.f9,
+    required this.f10,
+    required this.f11,
+    required this.f12,
+    required this.f13,
+    required this.f14,
+    required this.f15,
+    required this.f16,
+    required this.f17,
+    required this.f18,
+    required this.f19,
+    required this.f20,
+    required this.f21,
+  });
+
+  factory TwentyOne.fromJson(Map<String, dynamic> json) {
+    return TwentyOne(
+      f1: (json['f1'] as num).toInt(),
+      f2: (json['f2'] as num).toInt(),
+      f3: (json['f3'] as num).toInt(),
+      f4: (json['f4'] as num).toInt(),
+      f5: (json['f5'] as num).toInt(),
+      f6: (json['f6'] as num).toInt(),
+      f7: (json['f7'] as num).toInt(),
+      f8: (json['f8'] as num).toInt(),
+      f9: (json['f9'] as num).toInt(),
+      f10: (json['f10'] as num).toInt(),
+      f11: (json['f11'] as num).toInt(),
+      f12: (json['f12'] as num).toInt(),
+      f13: (json['f13'] as num).toInt(),
+      f14: (json['f14'] as num).toInt(),
+      f15: (json['f15'] as num).toInt(),
+      f16: (json['f16'] as num).toInt(),
+      f17: (json['f17'] as num).toInt(),
+      f18: (json['f18'] as num).toInt(),
+      f19: (json['f19'] as num).toInt(),
+      f20: (json['f20'] as num).toInt(),
+      f21: (json['f21'] as num).toInt(),
+    );
+  }
+
+  static List<TwentyOne> fromJsonList(List<dynamic> json) =>
+      json.map((e) => TwentyOne.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int f1;
+
+  @override
+  final int f2;
+
+  @override
+  final int f3;
+
+  @override
+  final int f4;
+
+  @override
+  final int f5;
+
+  @override
+  final int f6;
+
+  @override
+  final int f7;
+
+  @override
+  final int f8;
+
+  @override
+  final int f9;
+
+  @override
+  final int f10;
+
+  @override
+  final int f11;
+
+  @override
+  final int f12;
+
+  @override
+  final int f13;
+
+  @override
+  final int f14;
+
+  @override
+  final int f15;
+
+  @override
+  final int f16;
+
+  @override
+  final int f17;
+
+  @override
+  final int f18;
+
+  @override
+  final int f19;
+
+  @override
+  final int f20;
+
+  @override
+  final int f21;
+
+  Map<String, dynamic> toJson() => {
+    'f1': f1,
+    'f2': f2,
+    'f3': f3,
+    'f4': f4,
+    'f5': f5,
+    'f6': f6,
+    'f7': f7,
+    'f8': f8,
+    'f9': f9,
+    'f10': f10,
+    'f11': f11,
+    'f12': f12,
+    'f13': f13,
+    'f14': f14,
+    'f15': f15,
+    'f16': f16,
+    'f17': f17,
+    'f18': f18,
+    'f19': f19,
+    'f20': f20,
+    'f21': f21,
+  };
+
+  TwentyOne copyWith({
+    int? f1,
+    int? f2,
+    int? f3,
+    int? f4,
+    int? f5,
+    int? f6,
+    int? f7,
+    int? f8,
+    int? f9,
+    int? f10,
+    int? f11,
+    int? f12,
+    int? f13,
+    int? f14,
+    int? f15,
+    int? f16,
+    int? f17,
+    int? f18,
+    int? f19,
+    int? f20,
+    int? f21,
+  }) => TwentyOne(
+    f1: f1 ?? this.f1,
+    f2: f2 ?? this.f2,
+    f3: f3 ?? this.f3,
+    f4: f4 ?? this.f4,
+    f5: f5 ?? this.f5,
+    f6: f6 ?? this.f6,
+    f7: f7 ?? this.f7,
+    f8: f8 ?? this.f8,
+    f9: f9 ?? this.f9,
+    f10: f10 ?? this.f10,
+    f11: f11 ?? this.f11,
+    f12: f12 ?? this.f12,
+    f13: f13 ?? this.f13,
+    f14: f14 ?? this.f14,
+    f15: f15 ?? this.f15,
+    f16: f16 ?? this.f16,
+    f17: f17 ?? this.f17,
+    f18: f18 ?? this.f18,
+    f19: f19 ?? this.f19,
+    f20: f20 ?? this.f20,
+    f21: f21 ?? this.f21,
+  );
+
+  @override
+  String toString() => 'TwentyOne('
+    'f1: $f1 '
+    'f2: $f2 '
+    'f3: $f3 '
+    'f4: $f4 '
+    'f5: $f5 '
+    'f6: $f6 '
+    'f7: $f7 '
+    'f8: $f8 '
+    'f9: $f9 '
+    'f10: $f10 '
+    'f11: $f11 '
+    'f12: $f12 '
+    'f13: $f13 '
+    'f14: $f14 '
+    'f15: $f15 '
+    'f16: $f16 '
+    'f17: $f17 '
+    'f18: $f18 '
+    'f19: $f19 '
+    'f20: $f20 '
+    'f21: $f21 '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is TwentyOne
+      && other.f1 == f1
+      && other.f2 == f2
+      && other.f3 == f3
+      && other.f4 == f4
+      && other.f5 == f5
+      && other.f6 == f6
+      && other.f7 == f7
+      && other.f8 == f8
+      && other.f9 == f9
+      && other.f10 == f10
+      && other.f11 == f11
+      && other.f12 == f12
+      && other.f13 == f13
+      && other.f14 == f14
+      && other.f15 == f15
+      && other.f16 == f16
+      && other.f17 == f17
+      && other.f18 == f18
+      && other.f19 == f19
+      && other.f20 == f20
+      && other.f21 == f21;
+  }
+
+  @override
+  int get hashCode => Object.hashAll([
+    f1,
+    f2,
+    f3,
+    f4,
+    f5,
+    f6,
+    f7,
+    f8,
+    f9,
+    f10,
+    f11,
+    f12,
+    f13,
+    f14,
+    f15,
+    f16,
+    f17,
+    f18,
+    f19,
+    f20,
+    f21,
+  ]);
+}
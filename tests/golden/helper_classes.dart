@@ -0,0 +1,23 @@
+part 'helper_classes.flu.dart';
+
+// @flu
+abstract class _A {
+  String get a;
+}
+
+abstract class _Helper {
+  String get h;
+}
+
+class Other {
+  final int x = 1;
+}
+
+// @flu
+abstract class _B {
+  String get b;
+}
+
+abstract class _Trailing {
+  String get t;
+}
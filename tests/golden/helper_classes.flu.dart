@@ -0,0 +1,91 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'helper_classes.dart';
+
+class A extends _A {
+  A({
+    required this.a,
+  });
+
+  factory A.fromJson(Map<String, dynamic> json) {
+    return A(
+      a: json['a'] as String,
+    );
+  }
+
+  static List<A> fromJsonList(List<dynamic> json) =>
+      json.map((e) => A.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String a;
+
+  Map<String, dynamic> toJson() => {
+    'a': a,
+  };
+
+  A copyWith({
+    String? a,
+  }) => A(
+    a: a ?? this.a,
+  );
+
+  @override
+  String toString() => 'A('
+    'a: $a '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is A
+      && other.a == a;
+  }
+
+  @override
+  int get hashCode => a.hashCode;
+}
+
+class B extends _B {
+  B({
+    required this.b,
+  });
+
+  factory B.fromJson(Map<String, dynamic> json) {
+    return B(
+      b: json['b'] as String,
+    );
+  }
+
+  static List<B> fromJsonList(List<dynamic> json) =>
+      json.map((e) => B.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String b;
+
+  Map<String, dynamic> toJson() => {
+    'b': b,
+  };
+
+  B copyWith({
+    String? b,
+  }) => B(
+    b: b ?? this.b,
+  );
+
+  @override
+  String toString() => 'B('
+    'b: $b '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is B
+      && other.b == b;
+  }
+
+  @override
+  int get hashCode => b.hashCode;
+}
@@ -0,0 +1,17 @@
+// args: --include-private
+part 'include_private.flu.dart';
+
+// @flu
+abstract class User {
+  String get name;
+  int? get age;
+
+  User copyWith({String? name});
+}
+
+// @flu
+abstract class _Point {
+  const _Point();
+
+  double get x;
+}
@@ -0,0 +1,104 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'include_private.dart';
+
+const _sentinel = Object();
+
+class UserData {
+  const UserData({
+    required this.name,
+    required this.age,
+  });
+
+  factory UserData.fromJson(Map<String, dynamic> json) {
+    return UserData(
+      name: json['name'] as String,
+      age: (json['age'] as num?)?.toInt(),
+    );
+  }
+
+  static List<UserData> fromJsonList(List<dynamic> json) =>
+      json.map((e) => UserData.fromJson(e as Map<String, dynamic>)).toList();
+
+  final String name;
+
+  final int? age;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+    'age': age,
+  };
+
+  UserData copyWith({
+    String? name,
+    Object? age = _sentinel,
+  }) => UserData(
+    name: name ?? this.name,
+    age: identical(age, _sentinel) ? this.age : age as int?,
+  );
+
+  @override
+  String toString() => 'UserData('
+    'name: $name '
+    'age: $age '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is UserData
+      && other.name == name
+      && other.age == age;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    age,
+  );
+}
+
+class Point extends _Point {
+  const Point({
+    required this.x,
+  });
+
+  factory Point.fromJson(Map<String, dynamic> json) {
+    return Point(
+      x: (json['x'] as num).toDouble(),
+    );
+  }
+
+  static List<Point> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Point.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final double x;
+
+  Map<String, dynamic> toJson() => {
+    'x': x,
+  };
+
+  Point copyWith({
+    double? x,
+  }) => Point(
+    x: x ?? this.x,
+  );
+
+  @override
+  String toString() => 'Point('
+    'x: $x '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Point
+      && other.x == x;
+  }
+
+  @override
+  int get hashCode => x.hashCode;
+}
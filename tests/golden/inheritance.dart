@@ -0,0 +1,18 @@
+part 'inheritance.flu.dart';
+
+// @flu
+abstract class _Shape {
+  String get id;
+}
+
+// @flu
+// @flu: discriminator=type
+abstract class _Circle extends _Shape {
+  double get radius;
+}
+
+// @flu
+// @flu: discriminator=type:square
+abstract class _Square extends _Shape {
+  double get side;
+}
@@ -0,0 +1,170 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'inheritance.dart';
+
+class Shape extends _Shape {
+  Shape({
+    required this.id,
+  });
+
+  factory Shape.fromJson(Map<String, dynamic> json) {
+    return Shape(
+      id: json['id'] as String,
+    );
+  }
+
+  static _Shape fromJsonUnion(Map<String, dynamic> json) => switch (json['type']) {
+    'Circle' => Circle.fromJson(json),
+    'square' => Square.fromJson(json),
+    _ => throw ArgumentError.value(json['type'], 'type', 'unknown Shape type'),
+  };
+
+  static List<Shape> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Shape.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+  };
+
+  Shape copyWith({
+    String? id,
+  }) => Shape(
+    id: id ?? this.id,
+  );
+
+  @override
+  String toString() => 'Shape('
+    'id: $id '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Shape
+      && other.id == id;
+  }
+
+  @override
+  int get hashCode => id.hashCode;
+}
+
+class Circle extends _Circle {
+  Circle({
+    required this.id,
+    required this.radius,
+  });
+
+  factory Circle.fromJson(Map<String, dynamic> json) {
+    assert(json['type'] == 'Circle');
+    return Circle(
+      id: json['id'] as String,
+      radius: (json['radius'] as num).toDouble(),
+    );
+  }
+
+  static List<Circle> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Circle.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  @override
+  final double radius;
+
+  Map<String, dynamic> toJson() => {
+    'type': 'Circle',
+    'id': id,
+    'radius': radius,
+  };
+
+  Circle copyWith({
+    String? id,
+    double? radius,
+  }) => Circle(
+    id: id ?? this.id,
+    radius: radius ?? this.radius,
+  );
+
+  @override
+  String toString() => 'Circle('
+    'id: $id '
+    'radius: $radius '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Circle
+      && other.id == id
+      && other.radius == radius;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    radius,
+  );
+}
+
+class Square extends _Square {
+  Square({
+    required this.id,
+    required this.side,
+  });
+
+  factory Square.fromJson(Map<String, dynamic> json) {
+    assert(json['type'] == 'square');
+    return Square(
+      id: json['id'] as String,
+      side: (json['side'] as num).toDouble(),
+    );
+  }
+
+  static List<Square> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Square.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  @override
+  final double side;
+
+  Map<String, dynamic> toJson() => {
+    'type': 'square',
+    'id': id,
+    'side': side,
+  };
+
+  Square copyWith({
+    String? id,
+    double? side,
+  }) => Square(
+    id: id ?? this.id,
+    side: side ?? this.side,
+  );
+
+  @override
+  String toString() => 'Square('
+    'id: $id '
+    'side: $side '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Square
+      && other.id == id
+      && other.side == side;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    side,
+  );
+}
@@ -0,0 +1,20 @@
+part 'inheritance_order.flu.dart';
+
+// @flu
+abstract class _Entity {
+  String get id;
+  DateTime get createdAt;
+}
+
+// @flu
+abstract class _Person extends _Entity {
+  String get name;
+}
+
+// @flu
+// @flu: merge
+abstract class _Employee extends _Person {
+  String get role;
+  // redeclared, so it comes after the inherited fields
+  DateTime? get createdAt;
+}
@@ -0,0 +1,215 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'inheritance_order.dart';
+
+const _sentinel = Object();
+
+class Entity extends _Entity {
+  Entity({
+    required this.id,
+    required this.createdAt,
+  });
+
+  factory Entity.fromJson(Map<String, dynamic> json) {
+    return Entity(
+      id: json['id'] as String,
+      createdAt: DateTime.parse(json['createdAt'] as String),
+    );
+  }
+
+  static List<Entity> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Entity.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  @override
+  final DateTime createdAt;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+    'createdAt': createdAt.toIso8601String(),
+  };
+
+  Entity copyWith({
+    String? id,
+    DateTime? createdAt,
+  }) => Entity(
+    id: id ?? this.id,
+    createdAt: createdAt ?? this.createdAt,
+  );
+
+  @override
+  String toString() => 'Entity('
+    'id: $id '
+    'createdAt: $createdAt '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Entity
+      && other.id == id
+      && other.createdAt == createdAt;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    createdAt,
+  );
+}
+
+class Person extends _Person {
+  Person({
+    required this.id,
+    required this.createdAt,
+    required this.name,
+  });
+
+  factory Person.fromJson(Map<String, dynamic> json) {
+    return Person(
+      id: json['id'] as String,
+      createdAt: DateTime.parse(json['createdAt'] as String),
+      name: json['name'] as String,
+    );
+  }
+
+  static List<Person> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Person.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  @override
+  final DateTime createdAt;
+
+  @override
+  final String name;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+    'createdAt': createdAt.toIso8601String(),
+    'name': name,
+  };
+
+  Person copyWith({
+    String? id,
+    DateTime? createdAt,
+    String? name,
+  }) => Person(
+    id: id ?? this.id,
+    createdAt: createdAt ?? this.createdAt,
+    name: name ?? this.name,
+  );
+
+  @override
+  String toString() => 'Person('
+    'id: $id '
+    'createdAt: $createdAt '
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Person
+      && other.id == id
+      && other.createdAt == createdAt
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    createdAt,
+    name,
+  );
+}
+
+class Employee extends _Employee {
+  Employee({
+    required this.id,
+    required this.name,
+    required this.role,
+    required this.createdAt,
+  });
+
+  factory Employee.fromJson(Map<String, dynamic> json) {
+    return Employee(
+      id: json['id'] as String,
+      name: json['name'] as String,
+      role: json['role'] as String,
+      createdAt: json['createdAt'] == null ? null : DateTime.parse(json['createdAt'] as String),
+    );
+  }
+
+  static List<Employee> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Employee.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  @override
+  final String name;
+
+  @override
+  final String role;
+
+  @override
+  final DateTime? createdAt;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+    'name': name,
+    'role': role,
+    'createdAt': createdAt?.toIso8601String(),
+  };
+
+  Employee copyWith({
+    String? id,
+    String? name,
+    String? role,
+    Object? createdAt = _sentinel,
+  }) => Employee(
+    id: id ?? this.id,
+    name: name ?? this.name,
+    role: role ?? this.role,
+    createdAt: identical(createdAt, _sentinel) ? this.createdAt : createdAt as DateTime?,
+  );
+
+  Employee merge(Employee other) => Employee(
+    id: other.id,
+    name: other.name,
+    role: other.role,
+    createdAt: other.createdAt ?? this.createdAt,
+  );
+
+  @override
+  String toString() => 'Employee('
+    'id: $id '
+    'name: $name '
+    'role: $role '
+    'createdAt: $createdAt '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Employee
+      && other.id == id
+      && other.name == name
+      && other.role == role
+      && other.createdAt == createdAt;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    name,
+    role,
+    createdAt,
+  );
+}
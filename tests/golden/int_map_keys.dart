@@ -0,0 +1,7 @@
+part 'int_map_keys.flu.dart';
+
+// @flu
+abstract class _Lookup {
+  Map<int, String> get names;
+  Map<int, String>? get aliases;
+}
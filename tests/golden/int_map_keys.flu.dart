@@ -0,0 +1,65 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'int_map_keys.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Lookup extends _Lookup {
+  Lookup({
+    required this.names,
+    required this.aliases,
+  });
+
+  factory Lookup.fromJson(Map<String, dynamic> json) {
+    return Lookup(
+      names: (json['names'] as Map<String, dynamic>).map((k, e) => MapEntry(int.parse(k), e as String)),
+      aliases: (json['aliases'] as Map<String, dynamic>?)?.map((k, e) => MapEntry(int.parse(k), e as String)),
+    );
+  }
+
+  static List<Lookup> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Lookup.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Map<int, String> names;
+
+  @override
+  final Map<int, String>? aliases;
+
+  Map<String, dynamic> toJson() => {
+    'names': names.map((k, e) => MapEntry(k.toString(), e)),
+    'aliases': aliases?.map((k, e) => MapEntry(k.toString(), e)),
+  };
+
+  Lookup copyWith({
+    Map<int, String>? names,
+    Object? aliases = _sentinel,
+  }) => Lookup(
+    names: names ?? this.names,
+    aliases: identical(aliases, _sentinel) ? this.aliases : aliases as Map<int, String>?,
+  );
+
+  @override
+  String toString() => 'Lookup('
+    'names: $names '
+    'aliases: $aliases '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Lookup
+      && const DeepCollectionEquality().equals(other.names, names)
+      && const DeepCollectionEquality().equals(other.aliases, aliases);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(names),
+    const DeepCollectionEquality().hash(aliases),
+  );
+}
@@ -0,0 +1,11 @@
+part 'iterables.flu.dart';
+
+// @flu
+abstract class _Feed {
+  const _Feed();
+
+  Iterable<String> get titles;
+  Iterable<int>? get counts;
+  Iterable<Item> get items;
+  Iterable<DateTime?> get times;
+}
@@ -0,0 +1,87 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'iterables.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Feed extends _Feed {
+  const Feed({
+    required this.titles,
+    required this.counts,
+    required this.items,
+    required this.times,
+  });
+
+  factory Feed.fromJson(Map<String, dynamic> json) {
+    return Feed(
+      titles: (json['titles'] as List).map((e) => e as String),
+      counts: (json['counts'] as List?)?.map((e) => (e as num).toInt()),
+      items: (json['items'] as List).map((e) => Item.fromJson(e as Map<String, dynamic>)),
+      times: (json['times'] as List).map((e) => e == null ? null : DateTime.parse(e as String)),
+    );
+  }
+
+  static List<Feed> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Feed.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Iterable<String> titles;
+
+  @override
+  final Iterable<int>? counts;
+
+  @override
+  final Iterable<Item> items;
+
+  @override
+  final Iterable<DateTime?> times;
+
+  Map<String, dynamic> toJson() => {
+    'titles': titles.toList(),
+    'counts': counts?.toList(),
+    'items': items.map((e) => e.toJson()).toList(),
+    'times': times.map((e) => e?.toIso8601String()).toList(),
+  };
+
+  Feed copyWith({
+    Iterable<String>? titles,
+    Object? counts = _sentinel,
+    Iterable<Item>? items,
+    Iterable<DateTime?>? times,
+  }) => Feed(
+    titles: titles ?? this.titles,
+    counts: identical(counts, _sentinel) ? this.counts : counts as Iterable<int>?,
+    items: items ?? this.items,
+    times: times ?? this.times,
+  );
+
+  @override
+  String toString() => 'Feed('
+    'titles: $titles '
+    'counts: $counts '
+    'items: $items '
+    'times: $times '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Feed
+      && const DeepCollectionEquality().equals(other.titles, titles)
+      && const DeepCollectionEquality().equals(other.counts, counts)
+      && const DeepCollectionEquality().equals(other.items, items)
+      && const DeepCollectionEquality().equals(other.times, times);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(titles),
+    const DeepCollectionEquality().hash(counts),
+    const DeepCollectionEquality().hash(items),
+    const DeepCollectionEquality().hash(times),
+  );
+}
@@ -0,0 +1,16 @@
+part 'json_key.flu.dart';
+
+// @flu
+abstract class _Event {
+  const _Event();
+
+  @JsonKey(name: 'created_at')
+  DateTime get createdAt;
+  @JsonKey(name: "event_type", defaultValue: 'click')
+  String get type;
+  // @flu key="explicit"
+  @JsonKey(name: 'ignored')
+  int get count;
+  @JsonKey(includeIfNull: false)
+  String? get label;
+}
@@ -0,0 +1,85 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'json_key.dart';
+
+const _sentinel = Object();
+
+class Event extends _Event {
+  const Event({
+    required this.createdAt,
+    required this.type,
+    required this.count,
+    required this.label,
+  });
+
+  factory Event.fromJson(Map<String, dynamic> json) {
+    return Event(
+      createdAt: DateTime.parse(json['created_at'] as String),
+      type: json['event_type'] as String,
+      count: (json['explicit'] as num).toInt(),
+      label: json['label'] as String?,
+    );
+  }
+
+  static List<Event> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Event.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final DateTime createdAt;
+
+  @override
+  final String type;
+
+  @override
+  final int count;
+
+  @override
+  final String? label;
+
+  Map<String, dynamic> toJson() => {
+    'created_at': createdAt.toIso8601String(),
+    'event_type': type,
+    'explicit': count,
+    'label': label,
+  };
+
+  Event copyWith({
+    DateTime? createdAt,
+    String? type,
+    int? count,
+    Object? label = _sentinel,
+  }) => Event(
+    createdAt: createdAt ?? this.createdAt,
+    type: type ?? this.type,
+    count: count ?? this.count,
+    label: identical(label, _sentinel) ? this.label : label as String?,
+  );
+
+  @override
+  String toString() => 'Event('
+    'createdAt: $createdAt '
+    'type: $type '
+    'count: $count '
+    'label: $label '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Event
+      && other.createdAt == createdAt
+      && other.type == type
+      && other.count == count
+      && other.label == label;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    createdAt,
+    type,
+    count,
+    label,
+  );
+}
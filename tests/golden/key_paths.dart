@@ -0,0 +1,15 @@
+part 'key_paths.flu.dart';
+
+// @flu
+abstract class _Profile {
+  const _Profile();
+
+  // @flu key="a.b"
+  String get dotted;
+  // @flu path="user.name"
+  String get userName;
+  // @flu path="user.home's.city"
+  String? get city;
+  // @flu key="$id"
+  int get id;
+}
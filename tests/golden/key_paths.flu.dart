@@ -0,0 +1,89 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'key_paths.dart';
+
+const _sentinel = Object();
+
+class Profile extends _Profile {
+  const Profile({
+    required this.dotted,
+    required this.userName,
+    required this.city,
+    required this.id,
+  });
+
+  factory Profile.fromJson(Map<String, dynamic> json) {
+    return Profile(
+      dotted: json['a.b'] as String,
+      userName: (json['user'] as Map<String, dynamic>?)?['name'] as String,
+      city: ((json['user'] as Map<String, dynamic>?)?['home\'s'] as Map<String, dynamic>?)?['city'] as String?,
+      id: (json['\$id'] as num).toInt(),
+    );
+  }
+
+  static List<Profile> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Profile.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String dotted;
+
+  @override
+  final String userName;
+
+  @override
+  final String? city;
+
+  @override
+  final int id;
+
+  Map<String, dynamic> toJson() => {
+    'a.b': dotted,
+    'user': {
+      'name': userName,
+      'home\'s': {
+        'city': city,
+      },
+    },
+    '\$id': id,
+  };
+
+  Profile copyWith({
+    String? dotted,
+    String? userName,
+    Object? city = _sentinel,
+    int? id,
+  }) => Profile(
+    dotted: dotted ?? this.dotted,
+    userName: userName ?? this.userName,
+    city: identical(city, _sentinel) ? this.city : city as String?,
+    id: id ?? this.id,
+  );
+
+  @override
+  String toString() => 'Profile('
+    'dotted: $dotted '
+    'userName: $userName '
+    'city: $city '
+    'id: $id '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Profile
+      && other.dotted == dotted
+      && other.userName == userName
+      && other.city == city
+      && other.id == id;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    dotted,
+    userName,
+    city,
+    id,
+  );
+}
@@ -0,0 +1,10 @@
+part 'map_keys.flu.dart';
+
+enum Status { active, inactive }
+
+// @flu
+abstract class _Keys {
+  Map<int, String> get byId;
+  Map<DateTime, int> get byDate;
+  Map<Status, int>? get byStatus;
+}
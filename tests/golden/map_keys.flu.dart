@@ -0,0 +1,76 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'map_keys.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Keys extends _Keys {
+  Keys({
+    required this.byId,
+    required this.byDate,
+    required this.byStatus,
+  });
+
+  factory Keys.fromJson(Map<String, dynamic> json) {
+    return Keys(
+      byId: (json['byId'] as Map<String, dynamic>).map((k, e) => MapEntry(int.parse(k), e as String)),
+      byDate: (json['byDate'] as Map<String, dynamic>).map((k, e) => MapEntry(DateTime.parse(k), (e as num).toInt())),
+      byStatus: (json['byStatus'] as Map<String, dynamic>?)?.map((k, e) => MapEntry(Status.values.byName(k), (e as num).toInt())),
+    );
+  }
+
+  static List<Keys> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Keys.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Map<int, String> byId;
+
+  @override
+  final Map<DateTime, int> byDate;
+
+  @override
+  final Map<Status, int>? byStatus;
+
+  Map<String, dynamic> toJson() => {
+    'byId': byId.map((k, e) => MapEntry(k.toString(), e)),
+    'byDate': byDate.map((k, e) => MapEntry(k.toIso8601String(), e)),
+    'byStatus': byStatus?.map((k, e) => MapEntry(k.name, e)),
+  };
+
+  Keys copyWith({
+    Map<int, String>? byId,
+    Map<DateTime, int>? byDate,
+    Object? byStatus = _sentinel,
+  }) => Keys(
+    byId: byId ?? this.byId,
+    byDate: byDate ?? this.byDate,
+    byStatus: identical(byStatus, _sentinel) ? this.byStatus : byStatus as Map<Status, int>?,
+  );
+
+  @override
+  String toString() => 'Keys('
+    'byId: $byId '
+    'byDate: $byDate '
+    'byStatus: $byStatus '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Keys
+      && const DeepCollectionEquality().equals(other.byId, byId)
+      && const DeepCollectionEquality().equals(other.byDate, byDate)
+      && const DeepCollectionEquality().equals(other.byStatus, byStatus);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(byId),
+    const DeepCollectionEquality().hash(byDate),
+    const DeepCollectionEquality().hash(byStatus),
+  );
+}
@@ -0,0 +1,14 @@
+part 'method_strings.flu.dart';
+
+// @flu
+abstract class _Logger {
+  const _Logger();
+
+  String get prefix;
+  void close() {
+    print('}');
+    print("{ not a block");
+  }
+  void open() { print('}}'); }
+  int get level;
+}
@@ -0,0 +1,61 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'method_strings.dart';
+
+class Logger extends _Logger {
+  const Logger({
+    required this.prefix,
+    required this.level,
+  });
+
+  factory Logger.fromJson(Map<String, dynamic> json) {
+    return Logger(
+      prefix: json['prefix'] as String,
+      level: (json['level'] as num).toInt(),
+    );
+  }
+
+  static List<Logger> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Logger.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String prefix;
+
+  @override
+  final int level;
+
+  Map<String, dynamic> toJson() => {
+    'prefix': prefix,
+    'level': level,
+  };
+
+  Logger copyWith({
+    String? prefix,
+    int? level,
+  }) => Logger(
+    prefix: prefix ?? this.prefix,
+    level: level ?? this.level,
+  );
+
+  @override
+  String toString() => 'Logger('
+    'prefix: $prefix '
+    'level: $level '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Logger
+      && other.prefix == prefix
+      && other.level == level;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    prefix,
+    level,
+  );
+}
@@ -0,0 +1,13 @@
+part 'multiline_fields.flu.dart';
+
+// @flu
+abstract class _Report {
+  const _Report();
+
+  int get id;
+  Map<String,
+      List<Address>>
+      get sections;
+  String
+      get title;
+}
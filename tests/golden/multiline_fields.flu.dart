@@ -0,0 +1,74 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'multiline_fields.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+class Report extends _Report {
+  const Report({
+    required this.id,
+    required this.sections,
+    required this.title,
+  });
+
+  factory Report.fromJson(Map<String, dynamic> json) {
+    return Report(
+      id: (json['id'] as num).toInt(),
+      sections: (json['sections'] as Map<String, dynamic>).map((k, e) => MapEntry(k, (e as List).map((e) => Address.fromJson(e as Map<String, dynamic>)).toList())),
+      title: json['title'] as String,
+    );
+  }
+
+  static List<Report> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Report.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int id;
+
+  @override
+  final Map<String, List<Address>> sections;
+
+  @override
+  final String title;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+    'sections': sections.map((k, e) => MapEntry(k, e.map((e) => e.toJson()).toList())),
+    'title': title,
+  };
+
+  Report copyWith({
+    int? id,
+    Map<String, List<Address>>? sections,
+    String? title,
+  }) => Report(
+    id: id ?? this.id,
+    sections: sections ?? this.sections,
+    title: title ?? this.title,
+  );
+
+  @override
+  String toString() => 'Report('
+    'id: $id '
+    'sections: $sections '
+    'title: $title '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Report
+      && other.id == id
+      && const DeepCollectionEquality().equals(other.sections, sections)
+      && other.title == title;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    id,
+    const DeepCollectionEquality().hash(sections),
+    title,
+  );
+}
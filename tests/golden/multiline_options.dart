@@ -0,0 +1,13 @@
+part 'multiline_options.flu.dart';
+
+enum Status { active, inactive }
+
+// @flu
+abstract class _Account {
+  // @flu key="created_at"
+  // @flu default=0
+  int get created;
+  // @flu values="active:A,inactive:I"
+  // @flu fallback=inactive
+  Status get status;
+}
@@ -0,0 +1,61 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'multiline_options.dart';
+
+class Account extends _Account {
+  Account({
+    this.created = 0,
+    required this.status,
+  });
+
+  factory Account.fromJson(Map<String, dynamic> json) {
+    return Account(
+      created: json['created_at'] == null ? 0 : (json['created_at'] as num).toInt(),
+      status: Status.values.singleWhere((v) => (const {Status.active: 'A', Status.inactive: 'I'}[v] ?? v.name) == json['status'] as String, orElse: () => Status.inactive),
+    );
+  }
+
+  static List<Account> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Account.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int created;
+
+  @override
+  final Status status;
+
+  Map<String, dynamic> toJson() => {
+    'created_at': created,
+    'status': const {Status.active: 'A', Status.inactive: 'I'}[status] ?? status.name,
+  };
+
+  Account copyWith({
+    int? created,
+    Status? status,
+  }) => Account(
+    created: created ?? this.created,
+    status: status ?? this.status,
+  );
+
+  @override
+  String toString() => 'Account('
+    'created: $created '
+    'status: $status '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Account
+      && other.created == created
+      && other.status == status;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    created,
+    status,
+  );
+}
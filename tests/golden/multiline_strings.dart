@@ -0,0 +1,18 @@
+part 'multiline_strings.flu.dart';
+
+// @flu
+abstract class _Template {
+  const _Template();
+
+  String get name;
+  String render() {
+    return '''
+}
+  int get notAField;
+Hello $name, it's ${name.length} letters {
+''';
+  }
+  String raw() => r"""
+}""";
+  int get version;
+}
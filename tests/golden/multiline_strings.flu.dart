@@ -0,0 +1,61 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'multiline_strings.dart';
+
+class Template extends _Template {
+  const Template({
+    required this.name,
+    required this.version,
+  });
+
+  factory Template.fromJson(Map<String, dynamic> json) {
+    return Template(
+      name: json['name'] as String,
+      version: (json['version'] as num).toInt(),
+    );
+  }
+
+  static List<Template> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Template.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  @override
+  final int version;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+    'version': version,
+  };
+
+  Template copyWith({
+    String? name,
+    int? version,
+  }) => Template(
+    name: name ?? this.name,
+    version: version ?? this.version,
+  );
+
+  @override
+  String toString() => 'Template('
+    'name: $name '
+    'version: $version '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Template
+      && other.name == name
+      && other.version == version;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    version,
+  );
+}
@@ -0,0 +1,8 @@
+part 'no_fields.flu.dart';
+
+// @flu
+abstract class _Marker {
+  const _Marker();
+
+  String get label => 'marker';
+}
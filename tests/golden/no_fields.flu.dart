@@ -0,0 +1,32 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'no_fields.dart';
+
+class Marker extends _Marker {
+  const Marker();
+
+  factory Marker.fromJson(Map<String, dynamic> json) {
+    return Marker();
+  }
+
+  static List<Marker> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Marker.fromJson(e as Map<String, dynamic>)).toList();
+
+  Map<String, dynamic> toJson() => {};
+
+  Marker copyWith() => Marker();
+
+  @override
+  String toString() => 'Marker()';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Marker;
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
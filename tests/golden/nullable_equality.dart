@@ -0,0 +1,14 @@
+part 'nullable_equality.flu.dart';
+
+// @flu
+abstract class _Nullables {
+  List<String>? get tags;
+  Map<String, int>? get counts;
+  Set<int>? get ids;
+  String? get name;
+}
+
+// @flu
+abstract class _NullableList {
+  List<int>? get values;
+}
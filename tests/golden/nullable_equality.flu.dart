@@ -0,0 +1,130 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'nullable_equality.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Nullables extends _Nullables {
+  Nullables({
+    required this.tags,
+    required this.counts,
+    required this.ids,
+    required this.name,
+  });
+
+  factory Nullables.fromJson(Map<String, dynamic> json) {
+    return Nullables(
+      tags: (json['tags'] as List?)?.map((e) => e as String).toList(),
+      counts: (json['counts'] as Map<String, dynamic>?)?.map((k, e) => MapEntry(k, (e as num).toInt())),
+      ids: (json['ids'] as List?)?.map((e) => (e as num).toInt()).toSet(),
+      name: json['name'] as String?,
+    );
+  }
+
+  static List<Nullables> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Nullables.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final List<String>? tags;
+
+  @override
+  final Map<String, int>? counts;
+
+  @override
+  final Set<int>? ids;
+
+  @override
+  final String? name;
+
+  Map<String, dynamic> toJson() => {
+    'tags': tags,
+    'counts': counts,
+    'ids': ids?.toList(),
+    'name': name,
+  };
+
+  Nullables copyWith({
+    Object? tags = _sentinel,
+    Object? counts = _sentinel,
+    Object? ids = _sentinel,
+    Object? name = _sentinel,
+  }) => Nullables(
+    tags: identical(tags, _sentinel) ? this.tags : tags as List<String>?,
+    counts: identical(counts, _sentinel) ? this.counts : counts as Map<String, int>?,
+    ids: identical(ids, _sentinel) ? this.ids : ids as Set<int>?,
+    name: identical(name, _sentinel) ? this.name : name as String?,
+  );
+
+  @override
+  String toString() => 'Nullables('
+    'tags: $tags '
+    'counts: $counts '
+    'ids: $ids '
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Nullables
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && const DeepCollectionEquality().equals(other.counts, counts)
+      && const DeepCollectionEquality().equals(other.ids, ids)
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(tags),
+    const DeepCollectionEquality().hash(counts),
+    const DeepCollectionEquality().hash(ids),
+    name,
+  );
+}
+
+class NullableList extends _NullableList {
+  NullableList({
+    required this.values,
+  });
+
+  factory NullableList.fromJson(Map<String, dynamic> json) {
+    return NullableList(
+      values: (json['values'] as List?)?.map((e) => (e as num).toInt()).toList(),
+    );
+  }
+
+  static List<NullableList> fromJsonList(List<dynamic> json) =>
+      json.map((e) => NullableList.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final List<int>? values;
+
+  Map<String, dynamic> toJson() => {
+    'values': values,
+  };
+
+  NullableList copyWith({
+    Object? values = _sentinel,
+  }) => NullableList(
+    values: identical(values, _sentinel) ? this.values : values as List<int>?,
+  );
+
+  @override
+  String toString() => 'NullableList('
+    'values: $values '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is NullableList
+      && const DeepCollectionEquality().equals(other.values, values);
+  }
+
+  @override
+  int get hashCode => const DeepCollectionEquality().hash(values);
+}
@@ -0,0 +1,9 @@
+part 'nullable_lists.flu.dart';
+
+// @flu
+abstract class _Scores {
+  List<int> get all;
+  List<int?> get sparse;
+  List<int>? get maybe;
+  List<int?>? get maybeSparse;
+}
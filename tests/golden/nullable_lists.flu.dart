@@ -0,0 +1,87 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'nullable_lists.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Scores extends _Scores {
+  Scores({
+    required this.all,
+    required this.sparse,
+    required this.maybe,
+    required this.maybeSparse,
+  });
+
+  factory Scores.fromJson(Map<String, dynamic> json) {
+    return Scores(
+      all: (json['all'] as List).map((e) => (e as num).toInt()).toList(),
+      sparse: (json['sparse'] as List).map((e) => (e as num?)?.toInt()).toList(),
+      maybe: (json['maybe'] as List?)?.map((e) => (e as num).toInt()).toList(),
+      maybeSparse: (json['maybeSparse'] as List?)?.map((e) => (e as num?)?.toInt()).toList(),
+    );
+  }
+
+  static List<Scores> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Scores.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final List<int> all;
+
+  @override
+  final List<int?> sparse;
+
+  @override
+  final List<int>? maybe;
+
+  @override
+  final List<int?>? maybeSparse;
+
+  Map<String, dynamic> toJson() => {
+    'all': all,
+    'sparse': sparse,
+    'maybe': maybe,
+    'maybeSparse': maybeSparse,
+  };
+
+  Scores copyWith({
+    List<int>? all,
+    List<int?>? sparse,
+    Object? maybe = _sentinel,
+    Object? maybeSparse = _sentinel,
+  }) => Scores(
+    all: all ?? this.all,
+    sparse: sparse ?? this.sparse,
+    maybe: identical(maybe, _sentinel) ? this.maybe : maybe as List<int>?,
+    maybeSparse: identical(maybeSparse, _sentinel) ? this.maybeSparse : maybeSparse as List<int?>?,
+  );
+
+  @override
+  String toString() => 'Scores('
+    'all: $all '
+    'sparse: $sparse '
+    'maybe: $maybe '
+    'maybeSparse: $maybeSparse '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Scores
+      && const DeepCollectionEquality().equals(other.all, all)
+      && const DeepCollectionEquality().equals(other.sparse, sparse)
+      && const DeepCollectionEquality().equals(other.maybe, maybe)
+      && const DeepCollectionEquality().equals(other.maybeSparse, maybeSparse);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(all),
+    const DeepCollectionEquality().hash(sparse),
+    const DeepCollectionEquality().hash(maybe),
+    const DeepCollectionEquality().hash(maybeSparse),
+  );
+}
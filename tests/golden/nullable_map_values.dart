@@ -0,0 +1,12 @@
+part 'nullable_map_values.flu.dart';
+
+// @flu
+abstract class _User {
+  String get name;
+}
+
+// @flu
+abstract class _Directory {
+  Map<String, User> get users;
+  Map<String, User?> get maybeUsers;
+}
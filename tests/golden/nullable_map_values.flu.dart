@@ -0,0 +1,106 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'nullable_map_values.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+class User extends _User {
+  User({
+    required this.name,
+  });
+
+  factory User.fromJson(Map<String, dynamic> json) {
+    return User(
+      name: json['name'] as String,
+    );
+  }
+
+  static List<User> fromJsonList(List<dynamic> json) =>
+      json.map((e) => User.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+  };
+
+  User copyWith({
+    String? name,
+  }) => User(
+    name: name ?? this.name,
+  );
+
+  @override
+  String toString() => 'User('
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is User
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => name.hashCode;
+}
+
+class Directory extends _Directory {
+  Directory({
+    required this.users,
+    required this.maybeUsers,
+  });
+
+  factory Directory.fromJson(Map<String, dynamic> json) {
+    return Directory(
+      users: (json['users'] as Map<String, dynamic>).map((k, e) => MapEntry(k, User.fromJson(e as Map<String, dynamic>))),
+      maybeUsers: (json['maybeUsers'] as Map<String, dynamic>).map((k, e) => MapEntry(k, e == null ? null : User.fromJson(e as Map<String, dynamic>))),
+    );
+  }
+
+  static List<Directory> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Directory.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Map<String, User> users;
+
+  @override
+  final Map<String, User?> maybeUsers;
+
+  Map<String, dynamic> toJson() => {
+    'users': users.map((k, e) => MapEntry(k, e.toJson())),
+    'maybeUsers': maybeUsers.map((k, e) => MapEntry(k, e?.toJson())),
+  };
+
+  Directory copyWith({
+    Map<String, User>? users,
+    Map<String, User?>? maybeUsers,
+  }) => Directory(
+    users: users ?? this.users,
+    maybeUsers: maybeUsers ?? this.maybeUsers,
+  );
+
+  @override
+  String toString() => 'Directory('
+    'users: $users '
+    'maybeUsers: $maybeUsers '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Directory
+      && const DeepCollectionEquality().equals(other.users, users)
+      && const DeepCollectionEquality().equals(other.maybeUsers, maybeUsers);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(users),
+    const DeepCollectionEquality().hash(maybeUsers),
+  );
+}
@@ -0,0 +1,11 @@
+part 'num_fields.flu.dart';
+
+// @flu
+abstract class _Measure {
+  const _Measure();
+
+  num get value;
+  num? get previous;
+  List<num> get samples;
+  List<num?>? get gaps;
+}
@@ -0,0 +1,87 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'num_fields.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Measure extends _Measure {
+  const Measure({
+    required this.value,
+    required this.previous,
+    required this.samples,
+    required this.gaps,
+  });
+
+  factory Measure.fromJson(Map<String, dynamic> json) {
+    return Measure(
+      value: json['value'] as num,
+      previous: json['previous'] as num?,
+      samples: (json['samples'] as List).map((e) => e as num).toList(),
+      gaps: (json['gaps'] as List?)?.map((e) => e as num?).toList(),
+    );
+  }
+
+  static List<Measure> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Measure.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final num value;
+
+  @override
+  final num? previous;
+
+  @override
+  final List<num> samples;
+
+  @override
+  final List<num?>? gaps;
+
+  Map<String, dynamic> toJson() => {
+    'value': value,
+    'previous': previous,
+    'samples': samples,
+    'gaps': gaps,
+  };
+
+  Measure copyWith({
+    num? value,
+    Object? previous = _sentinel,
+    List<num>? samples,
+    Object? gaps = _sentinel,
+  }) => Measure(
+    value: value ?? this.value,
+    previous: identical(previous, _sentinel) ? this.previous : previous as num?,
+    samples: samples ?? this.samples,
+    gaps: identical(gaps, _sentinel) ? this.gaps : gaps as List<num?>?,
+  );
+
+  @override
+  String toString() => 'Measure('
+    'value: $value '
+    'previous: $previous '
+    'samples: $samples '
+    'gaps: $gaps '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Measure
+      && other.value == value
+      && other.previous == previous
+      && const DeepCollectionEquality().equals(other.samples, samples)
+      && const DeepCollectionEquality().equals(other.gaps, gaps);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    value,
+    previous,
+    const DeepCollectionEquality().hash(samples),
+    const DeepCollectionEquality().hash(gaps),
+  );
+}
@@ -0,0 +1,10 @@
+part 'object_fields.flu.dart';
+
+// @flu
+abstract class _Payload {
+  Object get value;
+  Object? get extra;
+  List<Object?> get items;
+  Map<String, Object> get attributes;
+  dynamic get raw;
+}
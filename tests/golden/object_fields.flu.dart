@@ -0,0 +1,98 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'object_fields.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Payload extends _Payload {
+  Payload({
+    required this.value,
+    required this.extra,
+    required this.items,
+    required this.attributes,
+    required this.raw,
+  });
+
+  factory Payload.fromJson(Map<String, dynamic> json) {
+    return Payload(
+      value: json['value'] as Object,
+      extra: json['extra'],
+      items: (json['items'] as List).map((e) => e).toList(),
+      attributes: (json['attributes'] as Map<String, dynamic>).map((k, e) => MapEntry(k, e as Object)),
+      raw: json['raw'],
+    );
+  }
+
+  static List<Payload> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Payload.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Object value;
+
+  @override
+  final Object? extra;
+
+  @override
+  final List<Object?> items;
+
+  @override
+  final Map<String, Object> attributes;
+
+  @override
+  final dynamic raw;
+
+  Map<String, dynamic> toJson() => {
+    'value': value,
+    'extra': extra,
+    'items': items,
+    'attributes': attributes,
+    'raw': raw,
+  };
+
+  Payload copyWith({
+    Object? value,
+    Object? extra = _sentinel,
+    List<Object?>? items,
+    Map<String, Object>? attributes,
+    dynamic raw,
+  }) => Payload(
+    value: value ?? this.value,
+    extra: identical(extra, _sentinel) ? this.extra : extra as Object?,
+    items: items ?? this.items,
+    attributes: attributes ?? this.attributes,
+    raw: raw ?? this.raw,
+  );
+
+  @override
+  String toString() => 'Payload('
+    'value: $value '
+    'extra: $extra '
+    'items: $items '
+    'attributes: $attributes '
+    'raw: $raw '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Payload
+      && other.value == value
+      && other.extra == extra
+      && const DeepCollectionEquality().equals(other.items, items)
+      && const DeepCollectionEquality().equals(other.attributes, attributes)
+      && other.raw == raw;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    value,
+    extra,
+    const DeepCollectionEquality().hash(items),
+    const DeepCollectionEquality().hash(attributes),
+    raw,
+  );
+}
@@ -0,0 +1,11 @@
+// args: --optional-nullable
+part 'optional_nullable.flu.dart';
+
+// @flu
+abstract class _Contact {
+  String get name;
+  String? get email;
+  int get age;
+  // @flu default="n/a"
+  String? get phone;
+}
@@ -0,0 +1,85 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'optional_nullable.dart';
+
+const _sentinel = Object();
+
+class Contact extends _Contact {
+  Contact({
+    required this.name,
+    this.email,
+    required this.age,
+    this.phone = 'n/a',
+  });
+
+  factory Contact.fromJson(Map<String, dynamic> json) {
+    return Contact(
+      name: json['name'] as String,
+      email: json['email'] as String?,
+      age: (json['age'] as num).toInt(),
+      phone: json['phone'] == null ? 'n/a' : json['phone'] as String?,
+    );
+  }
+
+  static List<Contact> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Contact.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  @override
+  final String? email;
+
+  @override
+  final int age;
+
+  @override
+  final String? phone;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+    'email': email,
+    'age': age,
+    'phone': phone,
+  };
+
+  Contact copyWith({
+    String? name,
+    Object? email = _sentinel,
+    int? age,
+    Object? phone = _sentinel,
+  }) => Contact(
+    name: name ?? this.name,
+    email: identical(email, _sentinel) ? this.email : email as String?,
+    age: age ?? this.age,
+    phone: identical(phone, _sentinel) ? this.phone : phone as String?,
+  );
+
+  @override
+  String toString() => 'Contact('
+    'name: $name '
+    'email: $email '
+    'age: $age '
+    'phone: $phone '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Contact
+      && other.name == name
+      && other.email == email
+      && other.age == age
+      && other.phone == phone;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    email,
+    age,
+    phone,
+  );
+}
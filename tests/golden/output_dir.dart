@@ -0,0 +1,9 @@
+// args: --output generated/models
+part 'generated/models/output_dir.flu.dart';
+
+// @flu
+abstract class _Item {
+  const _Item();
+
+  int get id;
+}
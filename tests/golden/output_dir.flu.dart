@@ -0,0 +1,48 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of '../../output_dir.dart';
+
+class Item extends _Item {
+  const Item({
+    required this.id,
+  });
+
+  factory Item.fromJson(Map<String, dynamic> json) {
+    return Item(
+      id: (json['id'] as num).toInt(),
+    );
+  }
+
+  static List<Item> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Item.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int id;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+  };
+
+  Item copyWith({
+    int? id,
+  }) => Item(
+    id: id ?? this.id,
+  );
+
+  @override
+  String toString() => 'Item('
+    'id: $id '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Item
+      && other.id == id;
+  }
+
+  @override
+  int get hashCode => id.hashCode;
+}
@@ -0,0 +1,17 @@
+part 'parsing.flu.dart';
+
+// @flu
+/// Documented abstract class.
+@immutable
+abstract class _Annotated {
+  String get first;
+  String get last;
+  String get fullName => '$first $last';
+  String get initials =>
+      '${first[0]}${last[0]}';
+} // end of _Annotated
+
+// @flu  
+abstract class _Next {
+  int get value;
+  void reset() {}}
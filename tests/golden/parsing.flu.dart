@@ -0,0 +1,104 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'parsing.dart';
+
+class Annotated extends _Annotated {
+  Annotated({
+    required this.first,
+    required this.last,
+  });
+
+  factory Annotated.fromJson(Map<String, dynamic> json) {
+    return Annotated(
+      first: json['first'] as String,
+      last: json['last'] as String,
+    );
+  }
+
+  static List<Annotated> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Annotated.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String first;
+
+  @override
+  final String last;
+
+  Map<String, dynamic> toJson() => {
+    'first': first,
+    'last': last,
+  };
+
+  Annotated copyWith({
+    String? first,
+    String? last,
+  }) => Annotated(
+    first: first ?? this.first,
+    last: last ?? this.last,
+  );
+
+  @override
+  String toString() => 'Annotated('
+    'first: $first '
+    'last: $last '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Annotated
+      && other.first == first
+      && other.last == last;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    first,
+    last,
+  );
+}
+
+class Next extends _Next {
+  Next({
+    required this.value,
+  });
+
+  factory Next.fromJson(Map<String, dynamic> json) {
+    return Next(
+      value: (json['value'] as num).toInt(),
+    );
+  }
+
+  static List<Next> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Next.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int value;
+
+  Map<String, dynamic> toJson() => {
+    'value': value,
+  };
+
+  Next copyWith({
+    int? value,
+  }) => Next(
+    value: value ?? this.value,
+  );
+
+  @override
+  String toString() => 'Next('
+    'value: $value '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Next
+      && other.value == value;
+  }
+
+  @override
+  int get hashCode => value.hashCode;
+}
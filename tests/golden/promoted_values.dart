@@ -0,0 +1,20 @@
+part 'promoted_values.flu.dart';
+
+enum Status { active, inactive }
+
+// @flu
+abstract class _Timeline<T> {
+  const _Timeline();
+
+  // @flu datetime=epochSeconds
+  List<DateTime?> get times;
+  // @flu datetime=epochSeconds
+  DateTime? get updatedAt;
+  List<T?> get entries;
+  T? get latest;
+  // @flu values="active:A,inactive:I"
+  List<Status?> get statuses;
+  // @flu values="active:A,inactive:I"
+  Status? get e;
+  List<(int, String)?> get pairs;
+}
@@ -0,0 +1,120 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'promoted_values.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Timeline<T> extends _Timeline<T> {
+  const Timeline({
+    required this.times,
+    required this.updatedAt,
+    required this.entries,
+    required this.latest,
+    required this.statuses,
+    required this.e,
+    required this.pairs,
+  });
+
+  factory Timeline.fromJson(Map<String, dynamic> json, T Function(Object?) fromJsonT) {
+    return Timeline(
+      times: (json['times'] as List).map((e) => e == null ? null : DateTime.fromMillisecondsSinceEpoch((e as int) * 1000)).toList(),
+      updatedAt: json['updatedAt'] == null ? null : DateTime.fromMillisecondsSinceEpoch((json['updatedAt'] as int) * 1000),
+      entries: (json['entries'] as List).map((e) => e == null ? null : fromJsonT(e)).toList(),
+      latest: json['latest'] == null ? null : fromJsonT(json['latest']),
+      statuses: (json['statuses'] as List).map((e) => e == null ? null : Status.values.singleWhere((v) => (const {Status.active: 'A', Status.inactive: 'I'}[v] ?? v.name) == e as String)).toList(),
+      e: json['e'] == null ? null : Status.values.singleWhere((v) => (const {Status.active: 'A', Status.inactive: 'I'}[v] ?? v.name) == json['e'] as String),
+      pairs: (json['pairs'] as List).map((e) => e == null ? null : (((e as List)[0] as num).toInt(), (e as List)[1] as String)).toList(),
+    );
+  }
+
+  static List<Timeline<T>> fromJsonList<T>(List<dynamic> json, T Function(Object?) fromJsonT) =>
+      json.map((e) => Timeline<T>.fromJson(e as Map<String, dynamic>, fromJsonT)).toList();
+
+  @override
+  final List<DateTime?> times;
+
+  @override
+  final DateTime? updatedAt;
+
+  @override
+  final List<T?> entries;
+
+  @override
+  final T? latest;
+
+  @override
+  final List<Status?> statuses;
+
+  @override
+  final Status? e;
+
+  @override
+  final List<(int, String)?> pairs;
+
+  Map<String, dynamic> toJson(Object? Function(T) toJsonT) => {
+    'times': times.map((e) => e == null ? null : e.millisecondsSinceEpoch ~/ 1000).toList(),
+    'updatedAt': updatedAt == null ? null : updatedAt!.millisecondsSinceEpoch ~/ 1000,
+    'entries': entries.map((e) => e == null ? null : toJsonT(e)).toList(),
+    'latest': latest == null ? null : toJsonT(latest!),
+    'statuses': statuses.map((e) => e == null ? null : const {Status.active: 'A', Status.inactive: 'I'}[e] ?? e.name).toList(),
+    'e': e == null ? null : const {Status.active: 'A', Status.inactive: 'I'}[e!] ?? e!.name,
+    'pairs': pairs.map((e) => e == null ? null : [e.$1, e.$2]).toList(),
+  };
+
+  Timeline<T> copyWith({
+    List<DateTime?>? times,
+    Object? updatedAt = _sentinel,
+    List<T?>? entries,
+    Object? latest = _sentinel,
+    List<Status?>? statuses,
+    Object? e = _sentinel,
+    List<(int, String)?>? pairs,
+  }) => Timeline(
+    times: times ?? this.times,
+    updatedAt: identical(updatedAt, _sentinel) ? this.updatedAt : updatedAt as DateTime?,
+    entries: entries ?? this.entries,
+    latest: identical(latest, _sentinel) ? this.latest : latest as T?,
+    statuses: statuses ?? this.statuses,
+    e: identical(e, _sentinel) ? this.e : e as Status?,
+    pairs: pairs ?? this.pairs,
+  );
+
+  @override
+  String toString() => 'Timeline('
+    'times: $times '
+    'updatedAt: $updatedAt '
+    'entries: $entries '
+    'latest: $latest '
+    'statuses: $statuses '
+    'e: $e '
+    'pairs: $pairs '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Timeline<T>
+      && const DeepCollectionEquality().equals(other.times, times)
+      && other.updatedAt == updatedAt
+      && const DeepCollectionEquality().equals(other.entries, entries)
+      && other.latest == latest
+      && const DeepCollectionEquality().equals(other.statuses, statuses)
+      && other.e == e
+      && const DeepCollectionEquality().equals(other.pairs, pairs);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    const DeepCollectionEquality().hash(times),
+    updatedAt,
+    const DeepCollectionEquality().hash(entries),
+    latest,
+    const DeepCollectionEquality().hash(statuses),
+    e,
+    const DeepCollectionEquality().hash(pairs),
+  );
+}
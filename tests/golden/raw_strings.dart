@@ -0,0 +1,12 @@
+part 'raw_strings.flu.dart';
+
+// @flu
+abstract class _Pattern {
+  const _Pattern();
+
+  String get source;
+  bool matches(String value) {
+    return RegExp(r'^\w+}$').hasMatch(value) || value == r'\' + '}';
+  }
+  int get flags;
+}
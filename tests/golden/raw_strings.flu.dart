@@ -0,0 +1,61 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'raw_strings.dart';
+
+class Pattern extends _Pattern {
+  const Pattern({
+    required this.source,
+    required this.flags,
+  });
+
+  factory Pattern.fromJson(Map<String, dynamic> json) {
+    return Pattern(
+      source: json['source'] as String,
+      flags: (json['flags'] as num).toInt(),
+    );
+  }
+
+  static List<Pattern> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Pattern.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String source;
+
+  @override
+  final int flags;
+
+  Map<String, dynamic> toJson() => {
+    'source': source,
+    'flags': flags,
+  };
+
+  Pattern copyWith({
+    String? source,
+    int? flags,
+  }) => Pattern(
+    source: source ?? this.source,
+    flags: flags ?? this.flags,
+  );
+
+  @override
+  String toString() => 'Pattern('
+    'source: $source '
+    'flags: $flags '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Pattern
+      && other.source == source
+      && other.flags == flags;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    source,
+    flags,
+  );
+}
@@ -0,0 +1,10 @@
+part 'records.flu.dart';
+
+// @flu
+abstract class _Place {
+  const _Place();
+
+  (double, double) get coord;
+  (int, String)? get code;
+  List<(String, DateTime)> get visits;
+}
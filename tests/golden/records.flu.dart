@@ -0,0 +1,76 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'records.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Place extends _Place {
+  const Place({
+    required this.coord,
+    required this.code,
+    required this.visits,
+  });
+
+  factory Place.fromJson(Map<String, dynamic> json) {
+    return Place(
+      coord: (((json['coord'] as List)[0] as num).toDouble(), ((json['coord'] as List)[1] as num).toDouble()),
+      code: json['code'] == null ? null : (((json['code'] as List)[0] as num).toInt(), (json['code'] as List)[1] as String),
+      visits: (json['visits'] as List).map((e) => ((e as List)[0] as String, DateTime.parse((e as List)[1] as String))).toList(),
+    );
+  }
+
+  static List<Place> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Place.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final (double, double) coord;
+
+  @override
+  final (int, String)? code;
+
+  @override
+  final List<(String, DateTime)> visits;
+
+  Map<String, dynamic> toJson() => {
+    'coord': [coord.$1, coord.$2],
+    'code': code == null ? null : [code!.$1, code!.$2],
+    'visits': visits.map((e) => [e.$1, e.$2.toIso8601String()]).toList(),
+  };
+
+  Place copyWith({
+    (double, double)? coord,
+    Object? code = _sentinel,
+    List<(String, DateTime)>? visits,
+  }) => Place(
+    coord: coord ?? this.coord,
+    code: identical(code, _sentinel) ? this.code : code as (int, String)?,
+    visits: visits ?? this.visits,
+  );
+
+  @override
+  String toString() => 'Place('
+    'coord: $coord '
+    'code: $code '
+    'visits: $visits '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Place
+      && other.coord == coord
+      && other.code == code
+      && const DeepCollectionEquality().equals(other.visits, visits);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    coord,
+    code,
+    const DeepCollectionEquality().hash(visits),
+  );
+}
@@ -0,0 +1,21 @@
+part 'renamed.flu.dart';
+
+// @flu
+// @flu: name=User
+abstract class _UserDto {
+  const _UserDto();
+
+  String get id;
+}
+
+// @flu
+// @flu: discriminator=kind
+abstract class _AnimalDto {
+  String get name;
+}
+
+// @flu
+// @flu: discriminator=kind, name=Dog
+abstract class _DogDto extends _AnimalDto {
+  bool get goodBoy;
+}
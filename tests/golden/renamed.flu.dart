@@ -0,0 +1,157 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'renamed.dart';
+
+class User extends _UserDto {
+  const User({
+    required this.id,
+  });
+
+  factory User.fromJson(Map<String, dynamic> json) {
+    return User(
+      id: json['id'] as String,
+    );
+  }
+
+  static List<User> fromJsonList(List<dynamic> json) =>
+      json.map((e) => User.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String id;
+
+  Map<String, dynamic> toJson() => {
+    'id': id,
+  };
+
+  User copyWith({
+    String? id,
+  }) => User(
+    id: id ?? this.id,
+  );
+
+  @override
+  String toString() => 'User('
+    'id: $id '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is User
+      && other.id == id;
+  }
+
+  @override
+  int get hashCode => id.hashCode;
+}
+
+class AnimalDto extends _AnimalDto {
+  AnimalDto({
+    required this.name,
+  });
+
+  factory AnimalDto.fromJson(Map<String, dynamic> json) {
+    assert(json['kind'] == 'AnimalDto');
+    return AnimalDto(
+      name: json['name'] as String,
+    );
+  }
+
+  static _AnimalDto fromJsonUnion(Map<String, dynamic> json) => switch (json['kind']) {
+    'AnimalDto' => AnimalDto.fromJson(json),
+    'Dog' => Dog.fromJson(json),
+    _ => throw ArgumentError.value(json['kind'], 'kind', 'unknown AnimalDto type'),
+  };
+
+  static List<AnimalDto> fromJsonList(List<dynamic> json) =>
+      json.map((e) => AnimalDto.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  Map<String, dynamic> toJson() => {
+    'kind': 'AnimalDto',
+    'name': name,
+  };
+
+  AnimalDto copyWith({
+    String? name,
+  }) => AnimalDto(
+    name: name ?? this.name,
+  );
+
+  @override
+  String toString() => 'AnimalDto('
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is AnimalDto
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => name.hashCode;
+}
+
+class Dog extends _DogDto {
+  Dog({
+    required this.name,
+    required this.goodBoy,
+  });
+
+  factory Dog.fromJson(Map<String, dynamic> json) {
+    assert(json['kind'] == 'Dog');
+    return Dog(
+      name: json['name'] as String,
+      goodBoy: json['goodBoy'] as bool,
+    );
+  }
+
+  static List<Dog> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Dog.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  @override
+  final bool goodBoy;
+
+  Map<String, dynamic> toJson() => {
+    'kind': 'Dog',
+    'name': name,
+    'goodBoy': goodBoy,
+  };
+
+  Dog copyWith({
+    String? name,
+    bool? goodBoy,
+  }) => Dog(
+    name: name ?? this.name,
+    goodBoy: goodBoy ?? this.goodBoy,
+  );
+
+  @override
+  String toString() => 'Dog('
+    'name: $name '
+    'goodBoy: $goodBoy '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Dog
+      && other.name == name
+      && other.goodBoy == goodBoy;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    goodBoy,
+  );
+}
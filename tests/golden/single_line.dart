@@ -0,0 +1,12 @@
+part 'single_line.flu.dart';
+
+// @flu
+abstract class _Empty {}
+
+// @flu
+abstract class _Spaced { }
+
+// @flu
+abstract class _After {
+  String get name;
+}
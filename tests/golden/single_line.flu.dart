@@ -0,0 +1,102 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'single_line.dart';
+
+class Empty extends _Empty {
+  Empty();
+
+  factory Empty.fromJson(Map<String, dynamic> json) {
+    return Empty();
+  }
+
+  static List<Empty> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Empty.fromJson(e as Map<String, dynamic>)).toList();
+
+  Map<String, dynamic> toJson() => {};
+
+  Empty copyWith() => Empty();
+
+  @override
+  String toString() => 'Empty()';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Empty;
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
+
+class Spaced extends _Spaced {
+  Spaced();
+
+  factory Spaced.fromJson(Map<String, dynamic> json) {
+    return Spaced();
+  }
+
+  static List<Spaced> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Spaced.fromJson(e as Map<String, dynamic>)).toList();
+
+  Map<String, dynamic> toJson() => {};
+
+  Spaced copyWith() => Spaced();
+
+  @override
+  String toString() => 'Spaced()';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Spaced;
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
+
+class After extends _After {
+  After({
+    required this.name,
+  });
+
+  factory After.fromJson(Map<String, dynamic> json) {
+    return After(
+      name: json['name'] as String,
+    );
+  }
+
+  static List<After> fromJsonList(List<dynamic> json) =>
+      json.map((e) => After.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String name;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+  };
+
+  After copyWith({
+    String? name,
+  }) => After(
+    name: name ?? this.name,
+  );
+
+  @override
+  String toString() => 'After('
+    'name: $name '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is After
+      && other.name == name;
+  }
+
+  @override
+  int get hashCode => name.hashCode;
+}
@@ -0,0 +1,18 @@
+// args: --standalone --raw-json
+enum Role { admin, member }
+
+// @flu
+abstract class _Member {
+  String get name;
+  Role get role;
+  List<String> get tags;
+  String? get nickname;
+
+  _Member copyWith({String? name});
+}
+
+// @flu
+// @flu: discriminator=type
+abstract class _Guest extends _Member {
+  DateTime get expiresAt;
+}
@@ -0,0 +1,186 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+import 'dart:convert';
+import 'package:collection/collection.dart';
+
+import 'standalone.dart';
+
+const _sentinel = Object();
+
+class Member {
+  const Member({
+    required this.name,
+    required this.role,
+    required this.tags,
+    required this.nickname,
+  });
+
+  factory Member.fromJson(Map<String, dynamic> json) {
+    return Member(
+      name: json['name'] as String,
+      role: Role.values.singleWhere((v) => v.name == json['role'] as String),
+      tags: (json['tags'] as List).map((e) => e as String).toList(),
+      nickname: json['nickname'] as String?,
+    );
+  }
+
+  static List<Member> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Member.fromJson(e as Map<String, dynamic>)).toList();
+
+  factory Member.fromRawJson(String str) =>
+      Member.fromJson(json.decode(str) as Map<String, dynamic>);
+
+  static List<Member> fromRawJsonList(String str) =>
+      fromJsonList(json.decode(str) as List<dynamic>);
+
+  final String name;
+
+  final Role role;
+
+  final List<String> tags;
+
+  final String? nickname;
+
+  Map<String, dynamic> toJson() => {
+    'name': name,
+    'role': role.name,
+    'tags': tags,
+    'nickname': nickname,
+  };
+
+  String toRawJson() => json.encode(toJson());
+
+  Member copyWith({
+    String? name,
+    Role? role,
+    List<String>? tags,
+    Object? nickname = _sentinel,
+  }) => Member(
+    name: name ?? this.name,
+    role: role ?? this.role,
+    tags: tags ?? this.tags,
+    nickname: identical(nickname, _sentinel) ? this.nickname : nickname as String?,
+  );
+
+  @override
+  String toString() => 'Member('
+    'name: $name '
+    'role: $role '
+    'tags: $tags '
+    'nickname: $nickname '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Member
+      && other.name == name
+      && other.role == role
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && other.nickname == nickname;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    role,
+    const DeepCollectionEquality().hash(tags),
+    nickname,
+  );
+}
+
+class Guest {
+  const Guest({
+    required this.name,
+    required this.role,
+    required this.tags,
+    required this.nickname,
+    required this.expiresAt,
+  });
+
+  factory Guest.fromJson(Map<String, dynamic> json) {
+    assert(json['type'] == 'Guest');
+    return Guest(
+      name: json['name'] as String,
+      role: Role.values.singleWhere((v) => v.name == json['role'] as String),
+      tags: (json['tags'] as List).map((e) => e as String).toList(),
+      nickname: json['nickname'] as String?,
+      expiresAt: DateTime.parse(json['expiresAt'] as String),
+    );
+  }
+
+  static List<Guest> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Guest.fromJson(e as Map<String, dynamic>)).toList();
+
+  factory Guest.fromRawJson(String str) =>
+      Guest.fromJson(json.decode(str) as Map<String, dynamic>);
+
+  static List<Guest> fromRawJsonList(String str) =>
+      fromJsonList(json.decode(str) as List<dynamic>);
+
+  final String name;
+
+  final Role role;
+
+  final List<String> tags;
+
+  final String? nickname;
+
+  final DateTime expiresAt;
+
+  Map<String, dynamic> toJson() => {
+    'type': 'Guest',
+    'name': name,
+    'role': role.name,
+    'tags': tags,
+    'nickname': nickname,
+    'expiresAt': expiresAt.toIso8601String(),
+  };
+
+  String toRawJson() => json.encode(toJson());
+
+  Guest copyWith({
+    String? name,
+    Role? role,
+    List<String>? tags,
+    Object? nickname = _sentinel,
+    DateTime? expiresAt,
+  }) => Guest(
+    name: name ?? this.name,
+    role: role ?? this.role,
+    tags: tags ?? this.tags,
+    nickname: identical(nickname, _sentinel) ? this.nickname : nickname as String?,
+    expiresAt: expiresAt ?? this.expiresAt,
+  );
+
+  @override
+  String toString() => 'Guest('
+    'name: $name '
+    'role: $role '
+    'tags: $tags '
+    'nickname: $nickname '
+    'expiresAt: $expiresAt '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Guest
+      && other.name == name
+      && other.role == role
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && other.nickname == nickname
+      && other.expiresAt == expiresAt;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    name,
+    role,
+    const DeepCollectionEquality().hash(tags),
+    nickname,
+    expiresAt,
+  );
+}
@@ -0,0 +1,13 @@
+part 'strict_int.flu.dart';
+
+// @flu
+abstract class _Counts {
+  // @flu strictInt
+  int get total;
+  // @flu strictInt
+  int? get maybe;
+  // @flu strictInt
+  List<int> get items;
+  // @flu strictInt coerce
+  int get parsed;
+}
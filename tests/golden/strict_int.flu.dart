@@ -0,0 +1,87 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'strict_int.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Counts extends _Counts {
+  Counts({
+    required this.total,
+    required this.maybe,
+    required this.items,
+    required this.parsed,
+  });
+
+  factory Counts.fromJson(Map<String, dynamic> json) {
+    return Counts(
+      total: switch (json['total'] as num) { final n when n == n.toInt() => n.toInt(), final n => throw FormatException('Expected an integer', n) },
+      maybe: json['maybe'] == null ? null : switch (json['maybe'] as num) { final n when n == n.toInt() => n.toInt(), final n => throw FormatException('Expected an integer', n) },
+      items: (json['items'] as List).map((e) => switch (e as num) { final n when n == n.toInt() => n.toInt(), final n => throw FormatException('Expected an integer', n) }).toList(),
+      parsed: switch (num.parse(json['parsed'].toString())) { final n when n == n.toInt() => n.toInt(), final n => throw FormatException('Expected an integer', n) },
+    );
+  }
+
+  static List<Counts> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Counts.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int total;
+
+  @override
+  final int? maybe;
+
+  @override
+  final List<int> items;
+
+  @override
+  final int parsed;
+
+  Map<String, dynamic> toJson() => {
+    'total': total,
+    'maybe': maybe,
+    'items': items,
+    'parsed': parsed,
+  };
+
+  Counts copyWith({
+    int? total,
+    Object? maybe = _sentinel,
+    List<int>? items,
+    int? parsed,
+  }) => Counts(
+    total: total ?? this.total,
+    maybe: identical(maybe, _sentinel) ? this.maybe : maybe as int?,
+    items: items ?? this.items,
+    parsed: parsed ?? this.parsed,
+  );
+
+  @override
+  String toString() => 'Counts('
+    'total: $total '
+    'maybe: $maybe '
+    'items: $items '
+    'parsed: $parsed '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Counts
+      && other.total == total
+      && other.maybe == maybe
+      && const DeepCollectionEquality().equals(other.items, items)
+      && other.parsed == parsed;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    total,
+    maybe,
+    const DeepCollectionEquality().hash(items),
+    parsed,
+  );
+}
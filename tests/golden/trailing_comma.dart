@@ -0,0 +1,21 @@
+// args: --trailing-comma never --merge
+part 'trailing_comma.flu.dart';
+
+// @flu
+// @flu: discriminator=kind
+abstract class _Shape {
+  // @flu path="style.color"
+  String get color;
+  // @flu path="style.width"
+  double? get width;
+}
+
+// @flu
+// @flu: discriminator=kind
+abstract class _Circle extends _Shape {
+  List<double> get center;
+  double get radius;
+}
+
+// @flu
+abstract class _Empty {}
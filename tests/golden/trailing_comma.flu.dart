@@ -0,0 +1,198 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'trailing_comma.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Shape extends _Shape {
+  Shape({
+    required this.color,
+    required this.width
+  });
+
+  factory Shape.fromJson(Map<String, dynamic> json) {
+    assert(json['kind'] == 'Shape');
+    return Shape(
+      color: (json['style'] as Map<String, dynamic>?)?['color'] as String,
+      width: ((json['style'] as Map<String, dynamic>?)?['width'] as num?)?.toDouble()
+    );
+  }
+
+  static _Shape fromJsonUnion(Map<String, dynamic> json) => switch (json['kind']) {
+    'Shape' => Shape.fromJson(json),
+    'Circle' => Circle.fromJson(json),
+    _ => throw ArgumentError.value(json['kind'], 'kind', 'unknown Shape type')
+  };
+
+  static List<Shape> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Shape.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String color;
+
+  @override
+  final double? width;
+
+  Map<String, dynamic> toJson() => {
+    'kind': 'Shape',
+    'style': {
+      'color': color,
+      'width': width
+    }
+  };
+
+  Shape copyWith({
+    String? color,
+    Object? width = _sentinel
+  }) => Shape(
+    color: color ?? this.color,
+    width: identical(width, _sentinel) ? this.width : width as double?
+  );
+
+  Shape merge(Shape other) => Shape(
+    color: other.color,
+    width: other.width ?? this.width
+  );
+
+  @override
+  String toString() => 'Shape('
+    'color: $color '
+    'width: $width '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Shape
+      && other.color == color
+      && other.width == width;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    color,
+    width
+  );
+}
+
+class Circle extends _Circle {
+  Circle({
+    required this.color,
+    required this.width,
+    required this.center,
+    required this.radius
+  });
+
+  factory Circle.fromJson(Map<String, dynamic> json) {
+    assert(json['kind'] == 'Circle');
+    return Circle(
+      color: (json['style'] as Map<String, dynamic>?)?['color'] as String,
+      width: ((json['style'] as Map<String, dynamic>?)?['width'] as num?)?.toDouble(),
+      center: (json['center'] as List).map((e) => (e as num).toDouble()).toList(),
+      radius: (json['radius'] as num).toDouble()
+    );
+  }
+
+  static List<Circle> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Circle.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String color;
+
+  @override
+  final double? width;
+
+  @override
+  final List<double> center;
+
+  @override
+  final double radius;
+
+  Map<String, dynamic> toJson() => {
+    'kind': 'Circle',
+    'style': {
+      'color': color,
+      'width': width
+    },
+    'center': center,
+    'radius': radius
+  };
+
+  Circle copyWith({
+    String? color,
+    Object? width = _sentinel,
+    List<double>? center,
+    double? radius
+  }) => Circle(
+    color: color ?? this.color,
+    width: identical(width, _sentinel) ? this.width : width as double?,
+    center: center ?? this.center,
+    radius: radius ?? this.radius
+  );
+
+  Circle merge(Circle other) => Circle(
+    color: other.color,
+    width: other.width ?? this.width,
+    center: other.center,
+    radius: other.radius
+  );
+
+  @override
+  String toString() => 'Circle('
+    'color: $color '
+    'width: $width '
+    'center: $center '
+    'radius: $radius '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Circle
+      && other.color == color
+      && other.width == width
+      && const DeepCollectionEquality().equals(other.center, center)
+      && other.radius == radius;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    color,
+    width,
+    const DeepCollectionEquality().hash(center),
+    radius
+  );
+}
+
+class Empty extends _Empty {
+  Empty();
+
+  factory Empty.fromJson(Map<String, dynamic> json) {
+    return Empty();
+  }
+
+  static List<Empty> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Empty.fromJson(e as Map<String, dynamic>)).toList();
+
+  Map<String, dynamic> toJson() => {};
+
+  Empty copyWith() => Empty();
+
+  Empty merge(Empty other) => Empty();
+
+  @override
+  String toString() => 'Empty()';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Empty;
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
@@ -0,0 +1,11 @@
+part 'trailing_marker.flu.dart';
+
+abstract class _Tag { // @flu
+  String get label;
+}
+
+abstract class _Ignored { // not annotated
+  String get label;
+}
+
+abstract class _Empty {} // @flu
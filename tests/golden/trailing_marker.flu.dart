@@ -0,0 +1,75 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'trailing_marker.dart';
+
+class Tag extends _Tag {
+  Tag({
+    required this.label,
+  });
+
+  factory Tag.fromJson(Map<String, dynamic> json) {
+    return Tag(
+      label: json['label'] as String,
+    );
+  }
+
+  static List<Tag> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Tag.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String label;
+
+  Map<String, dynamic> toJson() => {
+    'label': label,
+  };
+
+  Tag copyWith({
+    String? label,
+  }) => Tag(
+    label: label ?? this.label,
+  );
+
+  @override
+  String toString() => 'Tag('
+    'label: $label '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Tag
+      && other.label == label;
+  }
+
+  @override
+  int get hashCode => label.hashCode;
+}
+
+class Empty extends _Empty {
+  Empty();
+
+  factory Empty.fromJson(Map<String, dynamic> json) {
+    return Empty();
+  }
+
+  static List<Empty> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Empty.fromJson(e as Map<String, dynamic>)).toList();
+
+  Map<String, dynamic> toJson() => {};
+
+  Empty copyWith() => Empty();
+
+  @override
+  String toString() => 'Empty()';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Empty;
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
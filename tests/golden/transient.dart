@@ -0,0 +1,12 @@
+part 'transient.flu.dart';
+
+// @flu
+abstract class _Search {
+  String get query;
+  // @flu transient
+  String? get normalizedQuery;
+  // @flu transient default=0
+  int get page;
+  // @flu computed="query.length"
+  int get length;
+}
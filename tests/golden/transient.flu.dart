@@ -0,0 +1,77 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'transient.dart';
+
+const _sentinel = Object();
+
+class Search extends _Search {
+  Search({
+    required this.query,
+    required this.normalizedQuery,
+    this.page = 0,
+  }) : length = query.length;
+
+  factory Search.fromJson(Map<String, dynamic> json) {
+    return Search(
+      query: json['query'] as String,
+      normalizedQuery: null,
+    );
+  }
+
+  static List<Search> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Search.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String query;
+
+  @override
+  final String? normalizedQuery;
+
+  @override
+  final int page;
+
+  @override
+  final int length;
+
+  Map<String, dynamic> toJson() => {
+    'query': query,
+  };
+
+  Search copyWith({
+    String? query,
+    Object? normalizedQuery = _sentinel,
+    int? page,
+  }) => Search(
+    query: query ?? this.query,
+    normalizedQuery: identical(normalizedQuery, _sentinel) ? this.normalizedQuery : normalizedQuery as String?,
+    page: page ?? this.page,
+  );
+
+  @override
+  String toString() => 'Search('
+    'query: $query '
+    'normalizedQuery: $normalizedQuery '
+    'page: $page '
+    'length: $length '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Search
+      && other.query == query
+      && other.normalizedQuery == normalizedQuery
+      && other.page == page
+      && other.length == length;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    query,
+    normalizedQuery,
+    page,
+    length,
+  );
+}
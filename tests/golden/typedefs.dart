@@ -0,0 +1,11 @@
+part 'typedefs.flu.dart';
+
+typedef UserId = String;
+typedef UserIds = List<UserId>;
+
+// @flu
+abstract class _Team {
+  UserId get owner;
+  UserId? get deputy;
+  UserIds get members;
+}
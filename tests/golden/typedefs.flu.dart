@@ -0,0 +1,76 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'typedefs.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Team extends _Team {
+  Team({
+    required this.owner,
+    required this.deputy,
+    required this.members,
+  });
+
+  factory Team.fromJson(Map<String, dynamic> json) {
+    return Team(
+      owner: json['owner'] as String,
+      deputy: json['deputy'] as String?,
+      members: (json['members'] as List).map((e) => e as String).toList(),
+    );
+  }
+
+  static List<Team> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Team.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final String owner;
+
+  @override
+  final String? deputy;
+
+  @override
+  final List<String> members;
+
+  Map<String, dynamic> toJson() => {
+    'owner': owner,
+    'deputy': deputy,
+    'members': members,
+  };
+
+  Team copyWith({
+    String? owner,
+    Object? deputy = _sentinel,
+    List<String>? members,
+  }) => Team(
+    owner: owner ?? this.owner,
+    deputy: identical(deputy, _sentinel) ? this.deputy : deputy as String?,
+    members: members ?? this.members,
+  );
+
+  @override
+  String toString() => 'Team('
+    'owner: $owner '
+    'deputy: $deputy '
+    'members: $members '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Team
+      && other.owner == owner
+      && other.deputy == deputy
+      && const DeepCollectionEquality().equals(other.members, members);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    owner,
+    deputy,
+    const DeepCollectionEquality().hash(members),
+  );
+}
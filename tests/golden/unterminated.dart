@@ -0,0 +1,7 @@
+part 'unterminated.flu.dart';
+
+// @flu
+abstract class _Open {
+  const _Open();
+
+  String get name;
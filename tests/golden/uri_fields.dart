@@ -0,0 +1,11 @@
+part 'uri_fields.flu.dart';
+
+// @flu
+abstract class _Link {
+  const _Link();
+
+  Uri get href;
+  Uri? get icon;
+  List<Uri> get mirrors;
+  Map<String, Uri>? get alternates;
+}
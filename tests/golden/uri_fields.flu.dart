@@ -0,0 +1,87 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'uri_fields.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Link extends _Link {
+  const Link({
+    required this.href,
+    required this.icon,
+    required this.mirrors,
+    required this.alternates,
+  });
+
+  factory Link.fromJson(Map<String, dynamic> json) {
+    return Link(
+      href: Uri.parse(json['href'] as String),
+      icon: json['icon'] == null ? null : Uri.parse(json['icon'] as String),
+      mirrors: (json['mirrors'] as List).map((e) => Uri.parse(e as String)).toList(),
+      alternates: (json['alternates'] as Map<String, dynamic>?)?.map((k, e) => MapEntry(k, Uri.parse(e as String))),
+    );
+  }
+
+  static List<Link> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Link.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final Uri href;
+
+  @override
+  final Uri? icon;
+
+  @override
+  final List<Uri> mirrors;
+
+  @override
+  final Map<String, Uri>? alternates;
+
+  Map<String, dynamic> toJson() => {
+    'href': href.toString(),
+    'icon': icon?.toString(),
+    'mirrors': mirrors.map((e) => e.toString()).toList(),
+    'alternates': alternates?.map((k, e) => MapEntry(k, e.toString())),
+  };
+
+  Link copyWith({
+    Uri? href,
+    Object? icon = _sentinel,
+    List<Uri>? mirrors,
+    Object? alternates = _sentinel,
+  }) => Link(
+    href: href ?? this.href,
+    icon: identical(icon, _sentinel) ? this.icon : icon as Uri?,
+    mirrors: mirrors ?? this.mirrors,
+    alternates: identical(alternates, _sentinel) ? this.alternates : alternates as Map<String, Uri>?,
+  );
+
+  @override
+  String toString() => 'Link('
+    'href: $href '
+    'icon: $icon '
+    'mirrors: $mirrors '
+    'alternates: $alternates '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Link
+      && other.href == href
+      && other.icon == icon
+      && const DeepCollectionEquality().equals(other.mirrors, mirrors)
+      && const DeepCollectionEquality().equals(other.alternates, alternates);
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    href,
+    icon,
+    const DeepCollectionEquality().hash(mirrors),
+    const DeepCollectionEquality().hash(alternates),
+  );
+}
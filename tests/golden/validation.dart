@@ -0,0 +1,23 @@
+part 'validation.flu.dart';
+
+// @flu
+abstract class _Rating {
+  const _Rating();
+
+  // @flu min=0 max=5
+  int get stars;
+  // @flu minLength=1 maxLength=280
+  String get comment;
+  // @flu notEmpty
+  List<String> get tags;
+  // @flu min=0
+  double? get weight;
+  String get author;
+}
+
+// @flu
+abstract class _Unconstrained {
+  const _Unconstrained();
+
+  int get value;
+}
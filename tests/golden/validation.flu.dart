@@ -0,0 +1,150 @@
+// dart format off
+
+// ignore_for_file: avoid_equals_and_hash_code_on_mutable_classes, document_ignores, lines_longer_than_80_chars
+
+part of 'validation.dart';
+
+// requires `import 'package:collection/collection.dart';` in the library
+
+const _sentinel = Object();
+
+class Rating extends _Rating {
+  const Rating({
+    required this.stars,
+    required this.comment,
+    required this.tags,
+    required this.weight,
+    required this.author,
+  });
+
+  factory Rating.fromJson(Map<String, dynamic> json) {
+    return Rating(
+      stars: (json['stars'] as num).toInt(),
+      comment: json['comment'] as String,
+      tags: (json['tags'] as List).map((e) => e as String).toList(),
+      weight: (json['weight'] as num?)?.toDouble(),
+      author: json['author'] as String,
+    );
+  }
+
+  static List<Rating> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Rating.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int stars;
+
+  @override
+  final String comment;
+
+  @override
+  final List<String> tags;
+
+  @override
+  final double? weight;
+
+  @override
+  final String author;
+
+  Map<String, dynamic> toJson() => {
+    'stars': stars,
+    'comment': comment,
+    'tags': tags,
+    'weight': weight,
+    'author': author,
+  };
+
+  Rating copyWith({
+    int? stars,
+    String? comment,
+    List<String>? tags,
+    Object? weight = _sentinel,
+    String? author,
+  }) => Rating(
+    stars: stars ?? this.stars,
+    comment: comment ?? this.comment,
+    tags: tags ?? this.tags,
+    weight: identical(weight, _sentinel) ? this.weight : weight as double?,
+    author: author ?? this.author,
+  );
+
+  void validate() {
+    if (stars < 0) throw ArgumentError.value(stars, 'stars', 'must be at least 0');
+    if (stars > 5) throw ArgumentError.value(stars, 'stars', 'must be at most 5');
+    if (comment.length < 1) throw ArgumentError.value(comment, 'comment', 'must have a length of at least 1');
+    if (comment.length > 280) throw ArgumentError.value(comment, 'comment', 'must have a length of at most 280');
+    if (tags.isEmpty) throw ArgumentError.value(tags, 'tags', 'must not be empty');
+    if (weight != null && weight! < 0) throw ArgumentError.value(weight, 'weight', 'must be at least 0');
+  }
+
+  @override
+  String toString() => 'Rating('
+    'stars: $stars '
+    'comment: $comment '
+    'tags: $tags '
+    'weight: $weight '
+    'author: $author '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Rating
+      && other.stars == stars
+      && other.comment == comment
+      && const DeepCollectionEquality().equals(other.tags, tags)
+      && other.weight == weight
+      && other.author == author;
+  }
+
+  @override
+  int get hashCode => Object.hash(
+    stars,
+    comment,
+    const DeepCollectionEquality().hash(tags),
+    weight,
+    author,
+  );
+}
+
+class Unconstrained extends _Unconstrained {
+  const Unconstrained({
+    required this.value,
+  });
+
+  factory Unconstrained.fromJson(Map<String, dynamic> json) {
+    return Unconstrained(
+      value: (json['value'] as num).toInt(),
+    );
+  }
+
+  static List<Unconstrained> fromJsonList(List<dynamic> json) =>
+      json.map((e) => Unconstrained.fromJson(e as Map<String, dynamic>)).toList();
+
+  @override
+  final int value;
+
+  Map<String, dynamic> toJson() => {
+    'value': value,
+  };
+
+  Unconstrained copyWith({
+    int? value,
+  }) => Unconstrained(
+    value: value ?? this.value,
+  );
+
+  @override
+  String toString() => 'Unconstrained('
+    'value: $value '
+    ')';
+
+  @override
+  bool operator ==(Object other) {
+    if (identical(this, other)) return true;
+    return other is Unconstrained
+      && other.value == value;
+  }
+
+  @override
+  int get hashCode => value.hashCode;
+}
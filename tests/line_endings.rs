@@ -0,0 +1,24 @@
+//! Checks that sources with Windows line endings generate the same code.
+
+use rust_dart_gen::{Config, DartFile};
+
+const SOURCE: &str = "part 'user.flu.dart';\n\n// @flu\nabstract class _User {\n  const _User();\n\n  // @flu key=\"user_id\"\n  int get id;\n  String? get name;\n  List<String> get tags;\n}\n";
+
+#[test]
+fn crlf_source_generates_the_same_code() {
+    let config = Config::default();
+    let expected = DartFile::from_string(SOURCE, "user.dart", &config).generate_string(&config);
+    // with a stray `\r` left by converting the line endings twice
+    for ending in ["\r\n", "\r\r\n"] {
+        let file = DartFile::from_string(&SOURCE.replace('\n', ending), "user.dart", &config);
+        assert_eq!(file.classes.len(), 1, "{ending:?}");
+        let mut log = String::new();
+        file.report(&mut log);
+        assert_eq!(log, "", "{ending:?}");
+        let generated = file.generate_string(&config).unwrap();
+        assert!(generated.contains("final int id;"));
+        assert!(generated.contains("json['user_id']"));
+        assert!(!generated.contains('\r'));
+        assert_eq!(Some(generated), expected, "{ending:?}");
+    }
+}